@@ -0,0 +1,19 @@
+// 编译时生成资源: 按用到的文字裁剪字体, 把PNG图片转换成XBM, 编译 themes/ 下的主题
+use std::env;
+use std::path::PathBuf;
+
+#[path = "build/fonts.rs"]
+mod fonts;
+#[path = "build/images.rs"]
+mod images;
+#[path = "build/themes.rs"]
+mod themes;
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build");
+    fonts::generate(&out_dir);
+    images::generate(&out_dir);
+    themes::generate(&out_dir);
+}
@@ -0,0 +1,427 @@
+// 编译时字体子集化
+// 扫描每个字体的 text_sources 中字符串字面量用到的非ASCII字符, 加上全部可见ASCII字符,
+// 用u8g2自带的bdfconv把 fonts/ 下的BDF(或经otf2bdf转换的TTF)字体裁剪成只包含这些字形的u8g2字体,
+// 生成 $OUT_DIR/fonts.rs, 由 src/fonts.rs 引入
+// 找不到字体文件或bdfconv源码时使用 fonts/subset 中提交的裁剪结果, 并给出警告
+// 最后检查用这个字体绘制的文字是否都有字形, 缺字时编译失败
+use std::collections::BTreeSet;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+struct FontSpec {
+    // 生成的Rust常量名
+    name: &'static str,
+    // fonts/ 下的文件名(不含扩展名), 依次查找 .bdf .ttf .otf
+    file: &'static str,
+    // TTF/OTF 转换为BDF时的像素大小
+    pixel_size: u32,
+    // 提交到仓库中的裁剪结果, 设置 FONT_SUBSET_UPDATE=1 编译时用新裁剪的字体覆盖
+    subset: &'static str,
+    // 用这个字体绘制的文字所在的源文件, 只从这些文件的字符串字面量中收集字形, 并且必须都在字体中
+    text_sources: &'static [&'static str],
+}
+
+const FONTS: &[FontSpec] = &[FontSpec {
+    name: "UNIFONT_16",
+    file: "unifont",
+    pixel_size: 16,
+    subset: "fonts/subset/u8g2_font_unifont_16.c",
+    // Font::Text 绘制的标题, 星期, 月份和日期格式
+    text_sources: &["src/locale.rs", "src/date_format.rs"],
+}];
+
+const BDFCONV_DIR: &str = "u8g2_rs/u8g2/tools/font/bdfconv";
+
+pub fn generate(out_dir: &Path) {
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=fonts");
+    println!("cargo:rerun-if-env-changed=FONT_SUBSET_UPDATE");
+    let update = env::var_os("FONT_SUBSET_UPDATE").is_some_and(|v| v != "0");
+
+    let bdfconv = build_bdfconv(out_dir);
+    let mut out = String::from("// 由 build.rs 生成, 不要手动修改\n");
+    for font in FONTS {
+        let (c_file, origin) = match (&bdfconv, find_source(font)) {
+            (Some(bdfconv), Some(source)) => {
+                let c_file = subset(bdfconv, font, &source, &collect_glyphs(font), out_dir);
+                if update {
+                    fs::create_dir_all(Path::new(font.subset).parent().unwrap()).unwrap();
+                    fs::copy(&c_file, font.subset).unwrap();
+                }
+                (c_file, source)
+            }
+            (bdfconv, source) => {
+                let reason = match (bdfconv, source) {
+                    (None, _) => format!("bdfconv源码不存在({})", BDFCONV_DIR),
+                    _ => format!("fonts/{}.bdf/.ttf 不存在", font.file),
+                };
+                let c_file = PathBuf::from(font.subset);
+                assert!(
+                    c_file.exists(),
+                    "{}, 提交的裁剪结果 {} 也不存在. 按 fonts/README.md 准备字体文件和bdfconv后, \
+                     在 Firmware 目录下执行 FONT_SUBSET_UPDATE=1 cargo build --release 重新生成",
+                    reason,
+                    font.subset
+                );
+                println!(
+                    "cargo:warning={}, {}使用{}",
+                    reason,
+                    font.name,
+                    c_file.display()
+                );
+                (c_file.clone(), c_file)
+            }
+        };
+        let data = parse_c_array(&fs::read_to_string(&c_file).unwrap());
+        check_glyphs(font, &data, &origin);
+        writeln!(out, "pub static {}: &[u8] = &{:?};", font.name, data).unwrap();
+    }
+    fs::write(out_dir.join("fonts.rs"), out).unwrap();
+}
+
+// text_sources 中的字符串字面量没有对应的字形时编译失败, 否则屏幕上会缺字
+// 按所在的常量或函数分别列出, 比如 locale.rs 中每种语言一张表, 可以直接看出是哪种语言缺字
+fn check_glyphs(font: &FontSpec, data: &[u8], origin: &Path) {
+    let available = font_glyphs(data);
+    let mut errors = Vec::new();
+    for file in font.text_sources {
+        println!("cargo:rerun-if-changed={}", file);
+        let source = fs::read_to_string(file).unwrap();
+        for (item, body) in top_level_items(&source) {
+            let missing: BTreeSet<char> = string_literals(body)
+                .iter()
+                .flat_map(|literal| literal.chars())
+                .filter(|c| !c.is_control() && !available.contains(&(*c as u32)))
+                .collect();
+            if !missing.is_empty() {
+                errors.push(format!(
+                    "  {} {}: {}",
+                    file,
+                    item,
+                    missing.iter().collect::<String>()
+                ));
+            }
+        }
+    }
+    if !errors.is_empty() {
+        panic!(
+            "{}({})缺少以下文字的字形, 字体的准备见 fonts/README.md\n{}",
+            font.name,
+            origin.display(),
+            errors.join("\n")
+        );
+    }
+}
+
+// 按顶层的 const/static/fn 把源码分段, 返回 (名称, 源码)
+fn top_level_items(source: &str) -> Vec<(&str, &str)> {
+    let mut starts = Vec::new();
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let item = line.strip_prefix("pub ").unwrap_or(line);
+        let name = ["const ", "static ", "fn "]
+            .iter()
+            .find_map(|keyword| item.strip_prefix(keyword))
+            .and_then(|rest| {
+                rest.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .next()
+            });
+        if let Some(name) = name {
+            starts.push((offset, name));
+        }
+        offset += line.len();
+    }
+    let mut items = Vec::new();
+    for (i, (start, name)) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).map_or(source.len(), |(end, _)| *end);
+        items.push((*name, &source[*start..end]));
+    }
+    items
+}
+
+// 列出u8g2字体中所有字形的编码
+// 格式见u8g2的 u8g2_font_get_glyph_data: 23字节的文件头之后是8位编码的字形,
+// 每个字形以 [编码, 到下一个字形的偏移] 开头, 偏移为0时结束;
+// 文件头记录的位置处是Unicode查找表, 表中第一项的偏移指向第一个16位编码的字形,
+// 每个字形以 [编码高位, 编码低位, 偏移] 开头, 编码为0时结束
+fn font_glyphs(data: &[u8]) -> BTreeSet<u32> {
+    const HEADER_LEN: usize = 23;
+    let word = |pos: usize| ((data[pos] as usize) << 8) | data[pos + 1] as usize;
+    let mut glyphs = BTreeSet::new();
+    let mut pos = HEADER_LEN;
+    while data[pos + 1] != 0 {
+        glyphs.insert(data[pos] as u32);
+        pos += data[pos + 1] as usize;
+    }
+    let table = HEADER_LEN + word(21);
+    let mut pos = table + word(table);
+    while pos + 2 < data.len() && word(pos) != 0 {
+        glyphs.insert(word(pos) as u32);
+        pos += data[pos + 2] as usize;
+    }
+    glyphs
+}
+
+// 收集 text_sources 的字符串字面量中的非ASCII字符, 加上全部可见ASCII字符, 跳过注释和字符字面量
+fn collect_glyphs(font: &FontSpec) -> BTreeSet<char> {
+    let mut glyphs: BTreeSet<char> = (' '..='~').collect();
+    for file in font.text_sources {
+        let source = fs::read_to_string(file).unwrap();
+        for literal in string_literals(&source) {
+            glyphs.extend(literal.chars().filter(|c| !c.is_ascii() && !c.is_control()));
+        }
+    }
+    glyphs
+}
+
+fn string_literals(source: &str) -> Vec<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut literals = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i += 2;
+                while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+                    i += 1;
+                }
+                i += 2;
+            }
+            // 原始字符串 r"..." r#"..."#
+            'r' if matches!(chars.get(i + 1), Some('"') | Some('#'))
+                && (i == 0 || !(chars[i - 1].is_alphanumeric() || chars[i - 1] == '_')) =>
+            {
+                let mut hashes = 0;
+                i += 1;
+                while chars.get(i) == Some(&'#') {
+                    hashes += 1;
+                    i += 1;
+                }
+                if chars.get(i) != Some(&'"') {
+                    continue;
+                }
+                i += 1;
+                let mut literal = String::new();
+                while i < chars.len() {
+                    if chars[i] == '"' && (1..=hashes).all(|h| chars.get(i + h) == Some(&'#')) {
+                        i += 1 + hashes;
+                        break;
+                    }
+                    literal.push(chars[i]);
+                    i += 1;
+                }
+                literals.push(literal);
+            }
+            '"' => {
+                i += 1;
+                let mut literal = String::new();
+                while i < chars.len() && chars[i] != '"' {
+                    if chars[i] == '\\' {
+                        i += 1;
+                        // \u{XXXX}
+                        if chars.get(i) == Some(&'u') {
+                            let end = (i..chars.len()).find(|&j| chars[j] == '}').unwrap_or(i);
+                            let hex: String = chars[i + 2..end].iter().collect();
+                            if let Some(c) =
+                                u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32)
+                            {
+                                literal.push(c);
+                            }
+                            i = end;
+                        }
+                    } else {
+                        literal.push(chars[i]);
+                    }
+                    i += 1;
+                }
+                i += 1;
+                literals.push(literal);
+            }
+            // 字符字面量 'x' '\n', 生命周期 'a 只跳过引号
+            '\'' => {
+                if chars.get(i + 1) == Some(&'\\') {
+                    i += 2;
+                    while i < chars.len() && chars[i] != '\'' {
+                        i += 1;
+                    }
+                    i += 1;
+                } else if chars.get(i + 2) == Some(&'\'') {
+                    i += 3;
+                } else {
+                    i += 1;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    literals
+}
+
+// 用主机的C编译器编译bdfconv
+fn build_bdfconv(out_dir: &Path) -> Option<PathBuf> {
+    let dir = Path::new(BDFCONV_DIR);
+    let sources: Vec<PathBuf> = fs::read_dir(dir)
+        .ok()?
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().is_some_and(|e| e == "c"))
+        .collect();
+    if sources.is_empty() {
+        return None;
+    }
+    let host = env::var("HOST").unwrap();
+    let compiler = cc::Build::new()
+        .target(&host)
+        .host(&host)
+        .opt_level(2)
+        .cargo_metadata(false)
+        .get_compiler();
+    let exe = out_dir.join(format!("bdfconv{}", env::consts::EXE_SUFFIX));
+    let status = compiler
+        .to_command()
+        .args(&sources)
+        .arg("-o")
+        .arg(&exe)
+        .status()
+        .expect("failed to run host C compiler");
+    assert!(status.success(), "failed to build bdfconv");
+    Some(exe)
+}
+
+fn find_source(font: &FontSpec) -> Option<PathBuf> {
+    ["bdf", "ttf", "otf"]
+        .iter()
+        .map(|ext| Path::new("fonts").join(format!("{}.{}", font.file, ext)))
+        .find(|p| p.exists())
+}
+
+fn subset(
+    bdfconv: &Path,
+    font: &FontSpec,
+    source: &Path,
+    glyphs: &BTreeSet<char>,
+    out_dir: &Path,
+) -> PathBuf {
+    let bdf = if source.extension().is_some_and(|e| e == "bdf") {
+        source.to_path_buf()
+    } else {
+        // TTF/OTF 需要先用 otf2bdf 转成指定像素大小的BDF
+        let bdf = out_dir.join(format!("{}.bdf", font.file));
+        let status = Command::new("otf2bdf")
+            .arg("-p")
+            .arg(font.pixel_size.to_string())
+            .arg("-r")
+            .arg("72")
+            .arg("-o")
+            .arg(&bdf)
+            .arg(source)
+            .status()
+            .expect("otf2bdf is required to convert TTF/OTF fonts");
+        // otf2bdf 在部分字形缺失时也会返回非0, 以是否生成文件为准
+        assert!(bdf.exists(), "otf2bdf failed: {:?}", status);
+        bdf
+    };
+
+    // 字形列表写入map文件, 避免命令行过长
+    let map: Vec<String> = glyphs.iter().map(|c| format!("${:x}", *c as u32)).collect();
+    let map_file = out_dir.join(format!("{}.map", font.name));
+    fs::write(&map_file, map.join(",")).unwrap();
+
+    let c_name = format!("u8g2_font_{}", font.name.to_lowercase());
+    let c_file = out_dir.join(format!("{}.c", c_name));
+    let status = Command::new(bdfconv)
+        .args(["-f", "1", "-b", "0", "-M"])
+        .arg(&map_file)
+        .arg("-n")
+        .arg(&c_name)
+        .arg("-o")
+        .arg(&c_file)
+        .arg(&bdf)
+        .status()
+        .expect("failed to run bdfconv");
+    assert!(status.success(), "bdfconv failed for {}", font.name);
+    c_file
+}
+
+// 解析bdfconv生成的C代码: const uint8_t name[N] U8G2_FONT_SECTION(...) = "..." "...";
+fn parse_c_array(c: &str) -> Vec<u8> {
+    let size: usize = c
+        .split_once('[')
+        .and_then(|(_, rest)| rest.split_once(']'))
+        .and_then(|(n, _)| n.trim().parse().ok())
+        .expect("array size not found in bdfconv output");
+    let body = &c[c.find('=').expect("array body not found") + 1..];
+    let bytes = body.as_bytes();
+    let mut data = Vec::with_capacity(size);
+    let mut in_string = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        i += 1;
+        if !in_string {
+            match b {
+                b'"' => in_string = true,
+                b';' => break,
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = false,
+            b'\\' => {
+                let e = bytes[i];
+                i += 1;
+                match e {
+                    b'0'..=b'7' => {
+                        let mut value = (e - b'0') as u32;
+                        for _ in 0..2 {
+                            match bytes.get(i) {
+                                Some(d @ b'0'..=b'7') => {
+                                    value = value * 8 + (d - b'0') as u32;
+                                    i += 1;
+                                }
+                                _ => break,
+                            }
+                        }
+                        data.push(value as u8);
+                    }
+                    b'n' => data.push(b'\n'),
+                    b't' => data.push(b'\t'),
+                    b'r' => data.push(b'\r'),
+                    b'a' => data.push(0x07),
+                    b'b' => data.push(0x08),
+                    b'f' => data.push(0x0c),
+                    b'v' => data.push(0x0b),
+                    other => data.push(other),
+                }
+            }
+            _ => data.push(b),
+        }
+    }
+    // C字符串字面量末尾的NUL不属于数组
+    data.resize(size, 0);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 提交的裁剪结果缺字时在这里失败, 不必等到编译固件
+    #[test]
+    fn committed_subset_has_all_glyphs() {
+        // text_sources 和 subset 都是相对 Firmware 目录的路径
+        env::set_current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+        for font in FONTS {
+            let data = parse_c_array(&fs::read_to_string(font.subset).unwrap());
+            check_glyphs(font, &data, Path::new(font.subset));
+        }
+    }
+}
@@ -0,0 +1,217 @@
+// 把 assets/ 下的PNG图片转换成1位XBM数据, 生成 $OUT_DIR/images.rs, 由 src/assets.rs 引入
+// 透明部分按白色处理, 灰度按阈值或Floyd-Steinberg抖动转换成黑白
+// 每行单独做PackBits压缩, 格式说明见 src/assets.rs 中的 Image
+use std::env;
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::path::Path;
+
+#[allow(dead_code)]
+pub enum Mode {
+    // 亮度低于阈值的像素为黑色, 适合本来就是黑白的像素画
+    Threshold(u8),
+    // 误差扩散抖动, 适合照片等有灰度的图片
+    Dither,
+}
+
+struct ImageSpec {
+    // 生成的Rust常量名
+    name: &'static str,
+    // assets/ 下的文件名
+    file: &'static str,
+    mode: Mode,
+    // 允许比屏幕大, 显示时居中裁剪
+    crop: bool,
+}
+
+const IMAGES: &[ImageSpec] = &[
+    ImageSpec {
+        name: "NOPE_ANSWER",
+        file: "nope_answer.png",
+        mode: Mode::Threshold(128),
+        crop: false,
+    },
+    ImageSpec {
+        name: "YES_ANSWER",
+        file: "yes_answer.png",
+        mode: Mode::Threshold(128),
+        crop: false,
+    },
+    ImageSpec {
+        name: "PAIR",
+        file: "pair.png",
+        mode: Mode::Threshold(128),
+        crop: true,
+    },
+];
+
+// 与 src/assets.rs 中的 MAX_STRIDE 一致
+const MAX_STRIDE: usize = 64;
+
+// 当前屏幕的宽高, 与 display.rs 中 u8g2 的 setup 函数一致
+fn panel_size() -> (u32, u32) {
+    if env::var_os("CARGO_FEATURE_PANEL_IL3820").is_some() {
+        (296, 128)
+    } else {
+        (200, 200)
+    }
+}
+
+pub fn generate(out_dir: &Path) {
+    println!("cargo:rerun-if-changed=assets");
+    let mut out = String::from("// 由 build.rs 从 assets/ 生成, 不要手动修改\n");
+    for image in IMAGES {
+        let path = Path::new("assets").join(image.file);
+        write_image(&mut out, image.name, &path, &image.mode, image.crop);
+    }
+    fs::write(out_dir.join("images.rs"), out).unwrap();
+}
+
+// 转换一张图片, 写出名为 name 的 Image 常量
+pub fn write_image(out: &mut String, name: &str, path: &Path, mode: &Mode, crop: bool) {
+    let (panel_w, panel_h) = panel_size();
+    let file = path.display();
+    let (width, height, luma) = load_luma(path);
+    // 屏幕可以旋转, 横竖任意一个方向能放下即可
+    let fits = (width <= panel_w && height <= panel_h) || (width <= panel_h && height <= panel_w);
+    if !fits {
+        if crop {
+            println!(
+                "cargo:warning={} {}x{} 大于屏幕 {}x{}, 显示时会被裁剪",
+                file, width, height, panel_w, panel_h
+            );
+        } else {
+            panic!(
+                "{} is {}x{}, larger than the {}x{} panel",
+                file, width, height, panel_w, panel_h
+            );
+        }
+    }
+    assert!(
+        (width as usize).div_ceil(8) <= MAX_STRIDE,
+        "{} is {} pixels wide, more than {}",
+        file,
+        width,
+        MAX_STRIDE * 8
+    );
+    let ink = match mode {
+        Mode::Threshold(level) => luma.iter().map(|l| l < level).collect(),
+        Mode::Dither => dither(width as usize, height as usize, &luma),
+    };
+    let xbm = pack_xbm(width as usize, height as usize, &ink);
+    let data: Vec<u8> = xbm
+        .chunks((width as usize).div_ceil(8))
+        .flat_map(packbits)
+        .collect();
+    writeln!(
+        out,
+        "// {}: {} 字节, 压缩后 {} 字节",
+        file,
+        xbm.len(),
+        data.len()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub static {}: Image = Image {{ width: {}, height: {}, data: &{:?} }};",
+        name, width, height, data
+    )
+    .unwrap();
+}
+
+// 解码成8位亮度, 透明部分与白色混合
+fn load_luma(path: &Path) -> (u32, u32, Vec<u8>) {
+    let file = File::open(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    let mut decoder = png::Decoder::new(file);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().unwrap();
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).unwrap();
+    let channels = info.color_type.samples();
+    let luma = buf[..info.buffer_size()]
+        .chunks(channels)
+        .map(|px| {
+            let rgb =
+                |r: u8, g: u8, b: u8| (r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000;
+            let (l, a) = match *px {
+                [l] => (l as u32, 255),
+                [l, a] => (l as u32, a as u32),
+                [r, g, b] => (rgb(r, g, b), 255),
+                [r, g, b, a] => (rgb(r, g, b), a as u32),
+                _ => unreachable!(),
+            };
+            ((l * a + 255 * (255 - a)) / 255) as u8
+        })
+        .collect();
+    (info.width, info.height, luma)
+}
+
+fn dither(width: usize, height: usize, luma: &[u8]) -> Vec<bool> {
+    let mut level: Vec<i16> = luma.iter().map(|l| *l as i16).collect();
+    let mut ink = vec![false; width * height];
+    for y in 0..height {
+        for x in 0..width {
+            let i = y * width + x;
+            let black = level[i] < 128;
+            ink[i] = black;
+            let error = level[i] - if black { 0 } else { 255 };
+            let mut spread = |dx: isize, dy: usize, weight: i16| {
+                let nx = x as isize + dx;
+                if nx >= 0 && (nx as usize) < width && y + dy < height {
+                    level[(y + dy) * width + nx as usize] += error * weight / 16;
+                }
+            };
+            spread(1, 0, 7);
+            spread(-1, 1, 3);
+            spread(0, 1, 5);
+            spread(1, 1, 1);
+        }
+    }
+    ink
+}
+
+// XBM: 每行按字节对齐, 每个字节低位在左, 1为黑色
+fn pack_xbm(width: usize, height: usize, ink: &[bool]) -> Vec<u8> {
+    let stride = width.div_ceil(8);
+    let mut data = vec![0u8; stride * height];
+    for y in 0..height {
+        for x in 0..width {
+            if ink[y * width + x] {
+                data[y * stride + x / 8] |= 1 << (x % 8);
+            }
+        }
+    }
+    data
+}
+
+// PackBits: 连续3个以上相同的字节编码成重复包, 其余的放进原样复制包
+fn packbits(row: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut literal: Vec<u8> = Vec::new();
+    let flush = |out: &mut Vec<u8>, literal: &mut Vec<u8>| {
+        for chunk in literal.chunks(128) {
+            out.push((chunk.len() - 1) as u8);
+            out.extend_from_slice(chunk);
+        }
+        literal.clear();
+    };
+    let mut i = 0;
+    while i < row.len() {
+        let run = row[i..]
+            .iter()
+            .take(128)
+            .take_while(|b| **b == row[i])
+            .count();
+        if run >= 3 {
+            flush(&mut out, &mut literal);
+            out.push((1 - run as i16) as i8 as u8);
+            out.push(row[i]);
+            i += run;
+        } else {
+            literal.push(row[i]);
+            i += 1;
+        }
+    }
+    flush(&mut out, &mut literal);
+    out
+}
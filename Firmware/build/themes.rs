@@ -0,0 +1,142 @@
+// 把 themes/ 下的每个子目录编译成一个主题, 生成 $OUT_DIR/themes.rs, 由 src/theme.rs 引入
+// 每个主题目录包含 theme.txt 和用到的PNG图片, theme.txt 的格式见 themes/README.md
+// 目录按名称排序, 第n个主题的设置值为n, 0固定为内置的默认主题
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::images::{self, Mode};
+
+const WEEKDAYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+const FONTS: &[&str] = &[
+    "Text",
+    "Latin",
+    "Small",
+    "NumberSmall",
+    "NumberMedium",
+    "NumberLarge",
+];
+
+pub fn generate(out_dir: &Path) {
+    println!("cargo:rerun-if-changed=themes");
+    let mut dirs: Vec<_> = match fs::read_dir("themes") {
+        Ok(entries) => entries
+            .map(|e| e.unwrap().path())
+            .filter(|p| p.join("theme.txt").is_file())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    dirs.sort();
+
+    let mut out = String::from("// 由 build.rs 从 themes/ 生成, 不要手动修改\n");
+    let mut list = vec![String::from("&DEFAULT")];
+    for (index, dir) in dirs.iter().enumerate() {
+        let module = format!("theme_{}", index + 1);
+        writeln!(out, "mod {} {{\n    use super::*;", module).unwrap();
+        write_theme(&mut out, dir);
+        writeln!(out, "}}").unwrap();
+        list.push(format!("&{}::THEME", module));
+    }
+    writeln!(
+        out,
+        "pub static THEMES: &[&Theme] = &[{}];",
+        list.join(", ")
+    )
+    .unwrap();
+    fs::write(out_dir.join("themes.rs"), out).unwrap();
+}
+
+fn write_theme(out: &mut String, dir: &Path) {
+    let config = dir.join("theme.txt");
+    let text = fs::read_to_string(&config).unwrap();
+    let mut name = dir.file_name().unwrap().to_string_lossy().into_owned();
+    let mut mode = Mode::Threshold(128);
+    let mut default_image = None;
+    let mut verdicts: [Option<String>; 7] = Default::default();
+    let mut fonts = [String::from("Text"), String::from("Text")];
+    let mut params = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .map(|(k, v)| (k.trim(), v.trim()))
+            .unwrap_or_else(|| panic!("{}:{}: expected key = value", config.display(), number + 1));
+        let error = |what: &str| -> ! {
+            panic!("{}:{}: {} '{}'", config.display(), number + 1, what, value)
+        };
+        match key {
+            "name" => name = value.to_string(),
+            "dither" => {
+                mode = match value {
+                    "true" => Mode::Dither,
+                    "false" => Mode::Threshold(128),
+                    _ => error("expected true or false, got"),
+                }
+            }
+            "default" => default_image = Some(value.to_string()),
+            "title_font" | "date_font" => {
+                if !FONTS.contains(&value) {
+                    error("unknown font");
+                }
+                fonts[(key == "date_font") as usize] = value.to_string();
+            }
+            "date" | "title" | "landscape_date" | "landscape_title" => {
+                let point: Vec<u16> = value
+                    .split(',')
+                    .map(|v| {
+                        v.trim()
+                            .parse()
+                            .unwrap_or_else(|_| error("expected x,y, got"))
+                    })
+                    .collect();
+                if point.len() != 2 || point.iter().any(|v| *v > 1000) {
+                    error("expected x,y in 0..=1000, got");
+                }
+                params.push(format!("{}: ({}, {})", key, point[0], point[1]));
+            }
+            _ => match WEEKDAYS.iter().position(|day| *day == key) {
+                Some(day) => verdicts[day] = Some(value.to_string()),
+                None => panic!("{}:{}: unknown key '{}'", config.display(), number + 1, key),
+            },
+        }
+    }
+
+    // 同一个文件只转换一次, 常量按文件出现的顺序命名为 IMAGE_0, IMAGE_1...
+    // 不用文件名, 否则 "周五.png" 和 "周六.png" 这样的名字会生成同名的常量
+    let mut files: Vec<&String> = Vec::new();
+    let mut refs = Vec::new();
+    for (day, file) in verdicts.iter().enumerate() {
+        let file = file.as_ref().or(default_image.as_ref()).unwrap_or_else(|| {
+            panic!(
+                "{}: no image for {}, set default",
+                config.display(),
+                WEEKDAYS[day]
+            )
+        });
+        let index = match files.iter().position(|f| *f == file) {
+            Some(index) => index,
+            None => {
+                files.push(file);
+                let image = format!("IMAGE_{}", files.len() - 1);
+                images::write_image(out, &image, &dir.join(file), &mode, false);
+                files.len() - 1
+            }
+        };
+        refs.push(format!("&IMAGE_{}", index));
+    }
+    params.push(String::from("..DEFAULT_PARAMS"));
+    writeln!(
+        out,
+        "    pub static THEME: Theme = Theme {{ name: {:?}, verdicts: [{}], title_font: Font::{}, \
+         date_font: Font::{}, layout: LayoutParams {{ {} }} }};",
+        name,
+        refs.join(", "),
+        fonts[0],
+        fonts[1],
+        params.join(", ")
+    )
+    .unwrap();
+}
@@ -0,0 +1,89 @@
+/*
+  Fontname: -gnu-Unifont-Medium-R-Normal-Sans-16-160-75-75-c-80-ISO10646-1
+  Copyright: GNU Unifont 13.0.06, SIL Open Font License 1.1 / GPLv2+ with the GNU font embedding exception
+  Glyphs: 118/57086
+  BBX Build Mode: 0
+*/
+const uint8_t u8g2_font_unifont_16[1956] U8G2_FONT_SECTION("u8g2_font_unifont_16") = 
+  "v\0\3\2\4\5\4\5\6\17\20\0\376\12\376\13\377\1\226\3)\4\247 "
+  "\5\0\20\242!\10\241\30\2428$\2\"\10E\24\243\310\334\2#\17\246\22"
+  "\242\352i\30\222\250e\30\242\236\0$\22\247\22\242\13\7%\212\244p\36#"
+  "\251\62\210\31\0%\24\247\22\242\321\244\244\224DJ\32\247\211\224DIIS"
+  "\0&\22\247\22\242\332JY%\24\223HK\304$\223\246\0'\7A\30\243"
+  "\30\2(\14\303\366\241J\242$\352-\312\2)\15\303\364\241\310\242,\352%"
+  "J\"\0*\15w2\242+U\332\266\244\251\226\1+\14w2\242\213k\303"
+  "\220\305\65\0,\10B\326\241P\22\5-\7\24\224\242\30\2.\7\"\26\242"
+  "\30\2/\14\246\22\242-V\303\64\254\246\0\60\21\246\22\242\322\242$\324\246"
+  "D\211\66\61\211\62\11\61\13\245\24\242\312\244$\354\323 2\17\246\22\242\31"
+  "\222PL3-\254\246\303\0\63\20\246\22\242\31\222PL\2439\25\305dH"
+  "\0\64\20\246\22\242\14\265$\252dI\226\14cZ\1\65\17\246\22\2428\244"
+  "\325ANS1\31\22\0\66\17\246\22\242\232\302\64\35\224\320\61\31\22\0\67"
+  "\13\246\22\242\270\26\323b\332\48\20\246\22\242\31\222\320\230\14I\350\230\14"
+  "\11\09\16\246\22\242\31\222\320\230\14jc4\1:\10r6\242\30\342!"
+  ";\12\222\366\241\30b%Q\0<\10\225\24\242\314\272v=\11VR\242\270"
+  "\23\207\1>\11\225\22\242H\273u\4\77\17\246\22\242\31\222PL\303j\16"
+  "\246\21\0@\22\246\22\242\232\62)Q\222%R\"%\222\226x\10A\15\246"
+  "\22\242\322\242\226P\34\6\321\61B\16\246\22\242\30\224\3208,\241\343\260\0"
+  "C\15\246\22\242\31\222\320\332QL\206\4D\16\246\22\242\30\242,\11\375\226"
+  "\14\21\0E\15\246\22\2428\244\325AI[\207\1F\14\246\22\2428\244\325"
+  "AI\273\2G\16\246\22\242\31\222\320Z\32B\233\262\4H\13\246\22\242\10"
+  "\35\207A\364\30I\13\245\24\242\30\244\260\237\6\1J\15\247\22\242\32\304\270"
+  "\247,\312\262\15K\21\246\22\242\10\265$\252d\242\230dQ-\11\3L\11"
+  "\246\22\242H\373\353\60M\15\246\22\242\10\305i\210\26\217\216\1N\20\246\22"
+  "\242\10\267M\211\224HJ\244D;\6O\14\246\22\242\31\222\320\37\223!\1"
+  "P\15\246\22\242\30\224\3208,iW\0Q\26\267\362\241\31\242\60\11\223\60"
+  "\11\223\60\11\223DI$i\310\5R\20\246\22\242\30\224\3208,Q-\311"
+  "\222P\14S\15\246\22\242\31\222\320\354(&C\2T\12\247\22\2428dq"
+  "\177\3U\13\246\22\242\10\375c2$\0V\21\247\22\242H\255I\26eQ"
+  "V\11\223\64\316\0W\15\246\22\242\10\275\270LC4\212\1X\17\246\22\242"
+  "\10\305$j\23\265\250%\24\3Y\16\247\22\242H\325$\213\262J\32w\3"
+  "Z\13\246\22\242\270\26{M\207\1[\11\303\370\241\30\242\376i\\\14\246\22"
+  "\242H\343j\234\306\325\0]\11\303\362\241\230\372\247!^\11\66\62\243\322\242"
+  "$\14_\7\27\362\2418\4`\7\63T\243\310\12a\16\206\22\242\31\222\60"
+  "M\206\321\246,\1b\15\266\22\242H[\26Mt\334\224\5c\15\206\22\242"
+  "\31\222P\355\230\14\11\0d\14\266\22\242mY\264\321\233\262\4e\17\206\22"
+  "\242\31\222P\34\6\265\230\14\11\0f\14\265\22\242\223\302\322 \205=\1g"
+  "\23\266\322\241M\26-\311\222,\332\322!\11\305dH\0h\13\266\22\242H"
+  "[\26M\364\61i\13\265\24\242\12sD\354\323 j\14\325\322\241\254#b"
+  "\37\245H\2k\20\266\22\242H\333\222\250\222\211I\26\325\222\60l\11\265\24"
+  "\242\21\373\247Am\22\207\22\242X\224(\222\")\222\")\222\"\251\0n"
+  "\12\206\22\242H\26M\364\61o\14\206\22\242\31\222\320\307dH\0p\16\246"
+  "\322\241H\26Mt\334\224%M\1q\14\246\322\241Y\264\321\233\262\244\5r"
+  "\13\206\22\242H\26MT\273\2s\14\206\22\242\31\222PvL\206\4t\13"
+  "\245\22\242\12K\203\24v\25u\12\206\22\242\10\375\246,\1v\14\206\22\242"
+  "\10\215I\324\233(\1w\21\207\22\242H\245H\212\244H\212\244H\252X\0"
+  "x\16\206\22\242\10\305$\312D-JB1y\16\246\322\241\10=&\221%"
+  "\255\14\11\0z\12\206\22\242\270\206\275\16\3{\16\324\324\241R\262\60\213\212"
+  "\265(\13\5|\7\341\330\241\370 }\17\324\324\241\20\263(\13K\265\60K"
+  "$\0~\12\67\22\243\321\244HS\0\0\0\0\4\377\377\60o\36\333\24\302"
+  "\317\362,\316\222p\211\6)\217\362(\217\362(\211\206,\312\244ZR\333\0"
+  "N\0\10\37\320\302\370\1N\11\21\317\20\302\31\36r\376>\34t\376\373\360"
+  "\1N\214\15\257\60\302\32\16:\377\377\66|N\224!\357\360\301\31\36\362\34"
+  "\317\361\34\317\261\341\220#i\216\2449\222\346H\232\307y\234\307\331\360\1N"
+  "\312\"\17\321\301\317\361\34Nr0\313\241\64\217\302\64,\251\251N\34\356x"
+  "\16\347x\16\347p\16\347\0Qm\36\377\360\301\316)9%\307s\372\360\235"
+  "{\226ca\216\304y5G\302\34\213r0\1T\27%\375\322\301\35\246!"
+  "\216\312Q[\324\26\265E\245a\220rD\312\221AG\244dXr4Gs"
+  "(\311\301\10Th'\375\320\301\32\16RX\12K\311\60$QX\12K\303"
+  "A\312\241\322 \265EmQi\220\222\34Kr$\321\261\4V\333\35\335\362"
+  "\301\370\240\325\264\232V\323jZM\12\63)\35\224\34\332\61\35\34\276\203\1"
+  "W\37\36\377\360\301\317\361\34\317\361\34\317\361\34\32\16:\224\3439\236\3439"
+  "\236\3439\236\17\37Y)!\377\320\301\32\16:\224\3439\236\343\371\360=\207"
+  "\223\34Mr0\313\261:\224\346\325\34\212tP^t$\17\321\301\313\361\34"
+  "\37\16Z\232#i\36\347\320pH\303\34\12s(\214\207\357H\216\347x\216"
+  "\347x\14e\345\20\371\326\301x\310\275\17\7\335\367\341\240\7f\37 \355\362"
+  "\301\32\216y8\34\363p\270#9\222\345\310p\210\3028\315\221\341\216\344h"
+  "<<\4f/#\17\321\301\33\316y\234\307\3039\217\363x\270\23\207\3579"
+  "V\307\262a\315r(\211r$K\362t8f\334\65\377\320\301\35\222!\31"
+  "\302J\224DI\224D\225()%Q\22%Q\26\205C\24fQ2\\\""
+  "%\13\243h\30\224\250\26\16\321\60(Q-\307\206s\216\0g\10!\14\325"
+  "\301\33ny\226gy\226g\303-\317\362,\317\206[\236\345Q\216D9R"
+  "Ot(\1g\37\64\16\321\301\312r(\213\6e\30\222,\312\242,\312\242"
+  ",\32\244A\312\242,\312\242,\32\244,\312\242A\312\242l\270di\24F"
+  "Y\22&i\226\250\25\0g(!\17\321\301\317\361\34\317\361\34\31\36\362\35"
+  "L\352XR\207\332\263jXK+q=\307s<\7l4)\17\321\301\317"
+  "\361\34\317\361\60\207\302lP\244\34I\224\34\211\222\34\252\344H\26\345Y5"
+  "\254\245\351\234\243I\16\347\10\0pk%\17\321\301\317\361\34\317\261b\232\205"
+  "iV\15\2438\314\221\60\311\321$\7\263\34\253Ci^\315\241H\7\5\221"
+  "\321#\377\360\301\317\361\34Nr0\313\241\64\257&\303\220Dj\252\347\320p"
+  "\320\241\34k\217\262<*\16\37\0\0";
@@ -0,0 +1,30 @@
+// 固件中不依赖HAL的模块按路径引入, 在主机上运行它们的 #[cfg(test)] 测试
+// 固件的 .cargo/config.toml 把默认target设成了riscv, 运行时需要指定主机的target:
+//   cargo test --manifest-path host_tests/Cargo.toml --target x86_64-unknown-linux-gnu
+#[path = "../src/panel.rs"]
+pub mod panel;
+
+#[path = "../src/layout.rs"]
+pub mod layout;
+
+#[path = "../src/text.rs"]
+pub mod text;
+
+#[path = "../src/locale.rs"]
+pub mod locale;
+
+#[path = "../src/time.rs"]
+pub mod time;
+
+#[path = "../src/date_format.rs"]
+pub mod date_format;
+
+#[path = "../src/waveform.rs"]
+pub mod waveform;
+
+#[path = "../src/thermal.rs"]
+pub mod thermal;
+
+// 构建脚本中的字体裁剪, 测试提交的裁剪结果是否缺字
+#[path = "../build/fonts.rs"]
+pub mod build_fonts;
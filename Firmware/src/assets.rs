@@ -1,470 +1,51 @@
-pub mod img {
-    pub static IMG_NOPE_ANSWER: [u8; 1936] = [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0x0F, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0x0F, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F,
-        0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00,
-        0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00, 0xC0,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF,
-        0x03, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF,
-        0x01, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03,
-        0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01,
-        0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00,
-        0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00,
-        0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00,
-        0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0x00,
-        0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0xF0, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0,
-        0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00,
-        0x00, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0xC0, 0xFF,
-        0x03, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00,
-        0x00, 0x00, 0xF8, 0xFF, 0xFF, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00,
-        0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0xC0, 0xFF, 0x03,
-        0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00,
-        0x00, 0xF8, 0xFF, 0xFF, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00,
-        0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0xC0, 0xFF, 0x03, 0x00,
-        0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00,
-        0xF8, 0xFF, 0xFF, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00,
-        0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0xC0, 0xFF, 0x03, 0x00, 0x00,
-        0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF8,
-        0xFF, 0xFF, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00,
-        0xF0, 0xFF, 0x00, 0x00, 0x00, 0xFC, 0xFF, 0xFF, 0xFF, 0xC0, 0xFF, 0xFF, 0x07, 0x00, 0xE0,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0xFC, 0x3F, 0xF0,
-        0xFF, 0x00, 0x80, 0xFF, 0x07, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0x00, 0x00, 0xFC, 0x3F, 0xF0, 0xFF, 0x00, 0x80, 0xFF, 0x07, 0x00, 0xE0, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0xFC, 0x3F, 0xF0, 0xFF,
-        0x00, 0x80, 0xFF, 0x07, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0x00, 0x00, 0xFC, 0x3F, 0xF0, 0xFF, 0x00, 0x80, 0xFF, 0x07, 0x00, 0xE0, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0xFC, 0x3F, 0xF0, 0xFF, 0x00,
-        0x80, 0xFF, 0x07, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0x00, 0x00, 0xFC, 0x3F, 0xF0, 0xFF, 0x00, 0x80, 0xFF, 0x07, 0x00, 0xE0, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0xFC, 0x3F, 0xF0, 0xFF, 0x00, 0x80,
-        0xFF, 0x07, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00,
-        0x00, 0xFC, 0x3F, 0xF0, 0xFF, 0x00, 0x80, 0xFF, 0x07, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F, 0xF0, 0xFF, 0x00, 0x80, 0xFF,
-        0xFF, 0x0F, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0x1F, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE,
-        0x1F, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x1F, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0xFF,
-        0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x1F,
-        0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x1F,
-        0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x1F, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0x0F,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x1F, 0x00,
-        0xF0, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00,
-        0x00, 0x00, 0x00, 0xFF, 0xFF, 0x1F, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x1F, 0x00, 0xF0,
-        0xFF, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00, 0x00,
-        0x00, 0x00, 0xFF, 0xFF, 0x1F, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x1F, 0x00, 0xF0, 0xFF,
-        0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF,
-        0x03, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03,
-        0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00,
-        0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0xFE,
-        0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0x07, 0xFE, 0xFF,
-        0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0xC0, 0xFF, 0xFF, 0x07, 0xFE, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0x07, 0xFE, 0x1F, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xC0, 0xFF, 0xFF, 0x07, 0xFE, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0,
-        0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0x07, 0xFE, 0x1F, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0,
-        0xFF, 0xFF, 0x07, 0xFE, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0x07, 0xFE, 0x1F, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF,
-        0xFF, 0x07, 0xFE, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0x07, 0xFE, 0x1F, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00,
-        0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00,
-        0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0xE0, 0xFF, 0x01, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00,
-        0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xE0, 0xFF, 0x01, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00,
-        0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0x00, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0,
-        0xFF, 0x01, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00,
-    ];
-    pub static PAIR_IMG: [u8; 5000] = [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0xf8, 0xbf, 0x2b, 0x00, 0x00, 0xff, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0xf0, 0x0f, 0x00, 0x00, 0x00, 0xf8, 0xff, 0x3f, 0x00,
-        0x00, 0xff, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00,
-        0x00, 0xf0, 0x0f, 0x00, 0x00, 0x00, 0xf8, 0xef, 0x2a, 0x00, 0x00, 0xff, 0x00, 0x00, 0xff,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0xf0, 0x0f, 0x00, 0x00,
-        0x00, 0xf8, 0xff, 0x3f, 0x00, 0x00, 0xff, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0xf0, 0x0f, 0x00, 0x00, 0x00, 0xf8, 0xbb, 0x2a, 0x00,
-        0x00, 0xff, 0x00, 0x00, 0xbf, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xbb, 0x00, 0x00,
-        0x00, 0xf0, 0x0b, 0x00, 0x00, 0x00, 0xf8, 0xff, 0x37, 0x00, 0x00, 0xff, 0x00, 0x00, 0xff,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0xf0, 0x0f, 0x00, 0x00,
-        0x00, 0xf8, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0xee, 0x00, 0x00, 0x00, 0xf0, 0x0e, 0x00, 0x00, 0x00, 0xf8, 0x07, 0x00, 0x7f,
-        0x1c, 0xff, 0x00, 0x3f, 0xff, 0x00, 0xff, 0x81, 0xff, 0xc0, 0x07, 0x00, 0xff, 0xe0, 0x8f,
-        0x1f, 0xf0, 0x0f, 0xfe, 0x00, 0x00, 0xb8, 0x03, 0x00, 0x7f, 0x1f, 0xff, 0xc0, 0x3f, 0xab,
-        0xc0, 0xff, 0x0b, 0xff, 0xe0, 0x03, 0x00, 0xaa, 0xe0, 0xaf, 0x2a, 0xb0, 0x0a, 0x2a, 0x00,
-        0x00, 0xf8, 0x07, 0x00, 0x7f, 0x1f, 0xff, 0xe0, 0xff, 0x7f, 0xf0, 0xff, 0x1f, 0xff, 0xe0,
-        0x03, 0x00, 0xff, 0xe0, 0xff, 0x7f, 0xf0, 0x0f, 0x37, 0x00, 0x00, 0xe8, 0x02, 0x00, 0xff,
-        0x1f, 0xff, 0xe0, 0xff, 0xae, 0xf0, 0xff, 0x0e, 0xff, 0xf1, 0x03, 0x00, 0xaa, 0xe0, 0xef,
-        0xaa, 0xe0, 0x8a, 0x0a, 0x00, 0x00, 0xf8, 0xff, 0x05, 0xff, 0x1f, 0xff, 0xf0, 0xff, 0xff,
-        0xf8, 0xff, 0x3f, 0xfe, 0xf1, 0x01, 0x00, 0xff, 0xe0, 0xff, 0xdf, 0xf1, 0xcf, 0x0d, 0x00,
-        0x00, 0xb8, 0xaa, 0x02, 0xbb, 0x1b, 0xbb, 0xf0, 0x8b, 0xaa, 0x80, 0x83, 0x2a, 0xbe, 0xb3,
-        0x01, 0x00, 0xaa, 0xa0, 0x2b, 0xaa, 0xa0, 0xaa, 0x02, 0x00, 0x00, 0xf8, 0x77, 0x05, 0xff,
-        0x1f, 0xff, 0xf8, 0x0f, 0x77, 0x00, 0xc0, 0x37, 0xfe, 0xfb, 0x01, 0x00, 0x77, 0xe0, 0x1f,
-        0x56, 0x71, 0x77, 0x05, 0x00, 0x00, 0xa8, 0xaa, 0x02, 0xee, 0x02, 0xee, 0xf8, 0x06, 0xaa,
-        0x00, 0xac, 0x2a, 0xec, 0xea, 0x00, 0x00, 0xaa, 0xe0, 0x0e, 0xaa, 0xa0, 0xaa, 0x02, 0x00,
-        0x00, 0xf8, 0xdf, 0x05, 0xff, 0x01, 0xff, 0xf8, 0x07, 0x5d, 0xc0, 0xff, 0x1d, 0xfc, 0xff,
-        0x00, 0x00, 0xdd, 0xe0, 0x1f, 0x5c, 0xf1, 0x5d, 0x05, 0x00, 0x00, 0xa8, 0xaa, 0x02, 0xaa,
-        0x00, 0xaa, 0xb8, 0x02, 0xaa, 0xb0, 0xaa, 0x2a, 0xa8, 0xaa, 0x00, 0x00, 0xaa, 0xa0, 0x0a,
-        0x2a, 0xa0, 0xaa, 0x02, 0x00, 0x00, 0x78, 0x07, 0x00, 0xff, 0x00, 0xff, 0xf8, 0x07, 0x55,
-        0xf8, 0x7f, 0x15, 0xf8, 0x7f, 0x00, 0x00, 0x55, 0xe0, 0x17, 0x54, 0x71, 0x55, 0x05, 0x00,
-        0x00, 0xa8, 0x02, 0x00, 0xaa, 0x00, 0xaa, 0xe8, 0x02, 0xaa, 0xe8, 0x8a, 0x2a, 0xa8, 0x2a,
-        0x00, 0x00, 0xaa, 0xa0, 0x0a, 0x8a, 0xa0, 0xaa, 0x0a, 0x00, 0x00, 0xf8, 0x05, 0x00, 0xdd,
-        0x00, 0xdd, 0xf8, 0x07, 0x55, 0xfc, 0xc3, 0x15, 0xf0, 0x1d, 0x00, 0x00, 0x55, 0xe0, 0x1d,
-        0x54, 0x51, 0x55, 0x15, 0x00, 0x00, 0xa8, 0x02, 0x00, 0xaa, 0x00, 0xaa, 0xa8, 0x02, 0x22,
-        0xa8, 0x82, 0x22, 0xa0, 0x2a, 0x00, 0x00, 0x22, 0xa0, 0x0a, 0x02, 0xa0, 0x02, 0x02, 0x00,
-        0x00, 0x70, 0x05, 0x00, 0x55, 0x00, 0x55, 0x70, 0x07, 0x55, 0x7c, 0x43, 0x15, 0x60, 0x15,
-        0x00, 0x00, 0x55, 0x60, 0x15, 0x54, 0x51, 0x45, 0x15, 0x00, 0x00, 0xa8, 0x02, 0x00, 0xaa,
-        0x00, 0xaa, 0xa0, 0xaa, 0x8a, 0xa8, 0xaa, 0x08, 0xa0, 0x0a, 0x00, 0x00, 0x88, 0xa0, 0x0a,
-        0x08, 0xa0, 0x8a, 0x00, 0x00, 0x00, 0xd8, 0x05, 0x00, 0x55, 0x00, 0x55, 0xf0, 0x55, 0x55,
-        0xdc, 0x55, 0x15, 0x40, 0x15, 0x00, 0x00, 0x55, 0xc0, 0x15, 0x54, 0x51, 0x05, 0x55, 0x00,
-        0x00, 0xa8, 0x02, 0x00, 0x22, 0x00, 0x22, 0xa0, 0x2a, 0x02, 0xa8, 0x02, 0x00, 0x00, 0x02,
-        0x00, 0x00, 0x00, 0xa0, 0x02, 0x00, 0x20, 0x02, 0x00, 0x00, 0x00, 0x50, 0x05, 0x00, 0x55,
-        0x00, 0x55, 0x40, 0x55, 0x55, 0x50, 0x55, 0x15, 0x40, 0x05, 0x00, 0x00, 0x55, 0x40, 0x15,
-        0x14, 0x51, 0x05, 0x11, 0x00, 0x00, 0xa8, 0x02, 0x00, 0x88, 0x00, 0x88, 0x00, 0x0a, 0x00,
-        0xa0, 0x82, 0x00, 0x80, 0x08, 0x00, 0x00, 0x00, 0xa0, 0x0a, 0x00, 0x80, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x05,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x55, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xf8, 0xff, 0x1f, 0x1f, 0x00, 0xc0, 0x07, 0xe0, 0xfb, 0xff, 0x7f, 0xfe, 0xff, 0x1f, 0xfe,
-        0xff, 0x80, 0xff, 0x3f, 0xfe, 0xff, 0x9f, 0x0f, 0xc0, 0x07, 0xf8, 0xff, 0x3f, 0x1f, 0x00,
-        0xc0, 0x07, 0xe0, 0xfb, 0xff, 0x7f, 0xfe, 0xff, 0x9f, 0xff, 0xff, 0xe1, 0xff, 0x7f, 0xfe,
-        0xff, 0x9f, 0x0f, 0xc0, 0x07, 0xf8, 0xff, 0x7f, 0x1f, 0x00, 0xc0, 0x07, 0xe0, 0xfb, 0xff,
-        0x7f, 0xfe, 0xff, 0xdf, 0xff, 0xff, 0xf3, 0xff, 0xff, 0xfe, 0xff, 0x9f, 0x0f, 0xc0, 0x07,
-        0xf8, 0x00, 0x7c, 0x1f, 0x00, 0xc0, 0x07, 0xe0, 0x03, 0x00, 0x00, 0x00, 0x3e, 0xc0, 0x0f,
-        0xf0, 0xf3, 0x03, 0xfc, 0x00, 0x3e, 0x80, 0x0f, 0xc0, 0x07, 0xf8, 0x00, 0x7c, 0x1f, 0x00,
-        0xc0, 0x07, 0xe0, 0x03, 0x00, 0x00, 0x00, 0x3e, 0xc0, 0x07, 0xe0, 0xf3, 0x01, 0xf8, 0x00,
-        0x3e, 0x80, 0x0f, 0xc0, 0x07, 0xf8, 0x00, 0x7c, 0x1f, 0x00, 0xc0, 0x07, 0xe0, 0x03, 0x00,
-        0x00, 0x00, 0x3e, 0xc0, 0x07, 0xe0, 0xf3, 0x01, 0xf8, 0x00, 0x3e, 0x80, 0x0f, 0xc0, 0x07,
-        0xf8, 0xff, 0x3f, 0x1f, 0x00, 0xc0, 0x07, 0xe0, 0xfb, 0xff, 0x7f, 0x00, 0x3e, 0xc0, 0x07,
-        0xe0, 0xf3, 0x01, 0xf8, 0x00, 0x3e, 0x80, 0xff, 0xff, 0x07, 0xf8, 0xff, 0x1f, 0x1f, 0x00,
-        0xc0, 0x07, 0xe0, 0xfb, 0xff, 0x7f, 0x00, 0x3e, 0xc0, 0x07, 0xe0, 0xf3, 0x01, 0xf8, 0x00,
-        0x3e, 0x80, 0xff, 0xff, 0x07, 0xf8, 0xff, 0x3f, 0x1f, 0x00, 0xc0, 0x07, 0xe0, 0xfb, 0xff,
-        0x7f, 0x00, 0x3e, 0xc0, 0x07, 0xe0, 0xf3, 0x01, 0xf8, 0x00, 0x3e, 0x80, 0xff, 0xff, 0x07,
-        0xf8, 0x00, 0x7c, 0x1f, 0x00, 0xc0, 0x07, 0xe0, 0x03, 0x00, 0x00, 0x00, 0x3e, 0xc0, 0x07,
-        0xe0, 0xf3, 0x01, 0xf8, 0x00, 0x3e, 0x80, 0x0f, 0xc0, 0x07, 0xf8, 0x00, 0x7c, 0x1f, 0x00,
-        0xc0, 0x07, 0xe0, 0x03, 0x00, 0x00, 0x00, 0x3e, 0xc0, 0x07, 0xe0, 0xf3, 0x01, 0xf8, 0x00,
-        0x3e, 0x80, 0x0f, 0xc0, 0x07, 0xf8, 0x00, 0x7c, 0x1f, 0x00, 0xc0, 0x0f, 0xe0, 0x03, 0x00,
-        0x00, 0x00, 0x3e, 0xc0, 0x0f, 0xf0, 0xf3, 0x03, 0xfc, 0x00, 0x3e, 0x80, 0x0f, 0xc0, 0x07,
-        0xf8, 0xff, 0x7f, 0xff, 0xff, 0xcf, 0xff, 0xff, 0xfb, 0xff, 0x7f, 0x00, 0x3e, 0xc0, 0xff,
-        0xff, 0xf3, 0xff, 0xff, 0x00, 0x3e, 0x80, 0x0f, 0xc0, 0x07, 0xf8, 0xff, 0x3f, 0xff, 0xff,
-        0x8f, 0xff, 0xff, 0xfb, 0xff, 0x7f, 0x00, 0x3e, 0x80, 0xff, 0xff, 0xe1, 0xff, 0x7f, 0x00,
-        0x3e, 0x80, 0x0f, 0xc0, 0x07, 0xf8, 0xff, 0x1f, 0xff, 0xff, 0x0f, 0xff, 0xff, 0xfb, 0xff,
-        0x7f, 0x00, 0x3e, 0x00, 0xff, 0xff, 0xc0, 0xff, 0x3f, 0x00, 0x3e, 0x80, 0x0f, 0xc0, 0x07,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xa0, 0xaa, 0xaa, 0xea, 0xee, 0xff, 0xff, 0xff, 0x00, 0xfc,
-        0xff, 0xfc, 0xff, 0x80, 0x07, 0xf8, 0x00, 0xe7, 0x01, 0xde, 0x3d, 0xc0, 0xe3, 0xff, 0x07,
-        0x10, 0x51, 0x55, 0x55, 0x55, 0xdd, 0xfd, 0xff, 0x00, 0xfe, 0xff, 0xfe, 0xff, 0x80, 0x0f,
-        0xf8, 0x00, 0xe7, 0x03, 0xde, 0x7d, 0xc0, 0xf3, 0xff, 0x07, 0xa0, 0xaa, 0xaa, 0xbb, 0xff,
-        0xff, 0xff, 0xff, 0x00, 0xff, 0xff, 0xfe, 0xff, 0xc0, 0x0f, 0xf8, 0x01, 0xe7, 0x03, 0xde,
-        0xfd, 0xc0, 0xf3, 0xff, 0x07, 0x00, 0x44, 0x54, 0x55, 0x55, 0x55, 0x77, 0xff, 0x00, 0xff,
-        0xff, 0xff, 0xff, 0xc0, 0x1f, 0xf8, 0x03, 0xe7, 0x07, 0xde, 0xfd, 0xc0, 0xfb, 0xff, 0x07,
-        0xa0, 0xaa, 0xaa, 0xea, 0xee, 0xff, 0xff, 0xff, 0x00, 0x07, 0x00, 0x0f, 0x00, 0xc0, 0x1f,
-        0xf8, 0x03, 0xe7, 0x0f, 0xde, 0xfd, 0xc1, 0x7b, 0x00, 0x00, 0x10, 0x51, 0x55, 0x55, 0x55,
-        0xdd, 0xfd, 0xff, 0x00, 0x07, 0x00, 0x0f, 0x00, 0xe0, 0x1f, 0xf8, 0x07, 0xe7, 0x0f, 0xde,
-        0xfd, 0xc3, 0x7b, 0x00, 0x00, 0xa0, 0xaa, 0xaa, 0xbb, 0xff, 0xff, 0xff, 0xff, 0x00, 0x07,
-        0x00, 0x0f, 0x00, 0xe0, 0x3f, 0x78, 0x0f, 0xe7, 0x1e, 0xde, 0xfd, 0xc3, 0x7b, 0x00, 0x00,
-        0x00, 0x44, 0x54, 0x55, 0x55, 0x55, 0x77, 0xff, 0x00, 0xff, 0x3f, 0x0f, 0x00, 0xf0, 0x3d,
-        0x78, 0x0f, 0xe7, 0x3c, 0xde, 0xbd, 0xc7, 0x7b, 0x00, 0x00, 0xa0, 0xaa, 0xaa, 0xea, 0xee,
-        0xff, 0xff, 0xff, 0x00, 0xff, 0x7f, 0x0f, 0x00, 0xf0, 0x7c, 0x78, 0x3e, 0xe7, 0x7c, 0xde,
-        0x3d, 0xcf, 0x7b, 0x00, 0x00, 0x10, 0x51, 0x55, 0x55, 0x55, 0xdd, 0xfd, 0xff, 0x00, 0xfe,
-        0x7f, 0x0f, 0x00, 0xf0, 0x78, 0x78, 0x7c, 0xe7, 0xf8, 0xde, 0x3d, 0xdf, 0x7b, 0x00, 0x00,
-        0xa0, 0xaa, 0xaa, 0xbb, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0xf0, 0x0f, 0x00, 0xf8, 0xff,
-        0x78, 0xf0, 0xe7, 0xe0, 0xdf, 0x3d, 0xfc, 0x7b, 0x00, 0x07, 0x00, 0x44, 0x54, 0x55, 0x55,
-        0x55, 0x77, 0xff, 0x00, 0x00, 0xf0, 0x0f, 0x00, 0xfc, 0xff, 0x78, 0xf0, 0xe7, 0xc0, 0xdf,
-        0x3d, 0xf8, 0x7b, 0x00, 0x07, 0xa0, 0xaa, 0xaa, 0xea, 0xee, 0xff, 0xff, 0xff, 0x00, 0xff,
-        0xff, 0xff, 0xff, 0xfc, 0xff, 0x79, 0xe0, 0xe7, 0xc0, 0xdf, 0x3d, 0xf0, 0xfb, 0xff, 0x07,
-        0x10, 0x51, 0x55, 0x55, 0x55, 0xdd, 0xfd, 0xff, 0x00, 0xff, 0x7f, 0xfe, 0xff, 0x3c, 0xe0,
-        0x79, 0xc0, 0xe7, 0x80, 0xdf, 0x3d, 0xf0, 0xf3, 0xff, 0x07, 0xa0, 0xaa, 0xaa, 0xbb, 0xff,
-        0xff, 0xff, 0xff, 0x00, 0xff, 0x7f, 0xfe, 0xff, 0x3c, 0xe0, 0x79, 0x80, 0xe7, 0x00, 0xdf,
-        0x3d, 0xe0, 0xf3, 0xff, 0x07, 0x00, 0x44, 0x54, 0x55, 0x55, 0x55, 0x77, 0xff, 0x00, 0xff,
-        0x3f, 0xfc, 0xff, 0x1e, 0xe0, 0x7b, 0x80, 0xe7, 0x00, 0xdf, 0x3d, 0xc0, 0xe3, 0xff, 0x07,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3c, 0x01, 0x01, 0x00, 0x22, 0x08, 0x00, 0x40,
-        0x00, 0x00, 0x04, 0x90, 0x23, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x80, 0x04, 0x21, 0x01, 0x00, 0x22, 0x00, 0x00, 0x40, 0x00, 0x00, 0x04, 0x91, 0x24,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x20, 0xc7,
-        0x24, 0xf1, 0x4e, 0x32, 0xee, 0x99, 0x74, 0x08, 0x81, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x78, 0x29, 0x25, 0x21, 0xc8, 0x4b, 0x41,
-        0xa0, 0x97, 0xc8, 0x83, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x04, 0x20, 0xe9, 0x19, 0x21, 0xc8, 0x7b, 0x46, 0xb8, 0x97, 0x08, 0x81, 0x04,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x20, 0x29,
-        0x24, 0x22, 0x48, 0x0a, 0x48, 0xa4, 0x74, 0x04, 0x81, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0xc9, 0x24, 0xc2, 0x5e, 0x32, 0x87,
-        0xb9, 0x14, 0x04, 0x80, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x10, 0x02, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x09, 0x00, 0x3c, 0x63, 0x8c, 0x79, 0xcf, 0x98, 0x03,
-        0x30, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x02, 0x08, 0x00, 0x84, 0x94, 0x52, 0x42, 0x21, 0xa5, 0x04, 0x30, 0x03, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc2, 0x49, 0x06, 0x84, 0x10,
-        0x12, 0x42, 0x01, 0xa5, 0x04, 0x30, 0x03, 0xc0, 0xc1, 0xe1, 0x63, 0xcc, 0x0f, 0xf8, 0x0d,
-        0x38, 0x7c, 0x18, 0x03, 0x00, 0x02, 0x29, 0x09, 0x9c, 0x73, 0x1c, 0x21, 0x87, 0x98, 0x03,
-        0xf0, 0x3f, 0xe0, 0xe1, 0xf1, 0x67, 0xce, 0x1f, 0xf8, 0x0d, 0x38, 0xfe, 0x18, 0x03, 0x00,
-        0x02, 0x19, 0x0f, 0x84, 0x94, 0x90, 0x20, 0x48, 0xa4, 0x04, 0xf0, 0x3f, 0xe0, 0xe1, 0x31,
-        0x66, 0xc6, 0x18, 0x18, 0x0c, 0x6c, 0xc6, 0x18, 0x03, 0x00, 0x02, 0x29, 0x01, 0x84, 0x94,
-        0x52, 0x20, 0x28, 0xa4, 0x04, 0x00, 0x00, 0xb0, 0xb1, 0x31, 0x66, 0xc3, 0x18, 0x18, 0x0c,
-        0x6c, 0x06, 0x18, 0x03, 0x00, 0xde, 0x4b, 0x06, 0x04, 0x63, 0xcc, 0x23, 0xe7, 0x99, 0x04,
-        0x00, 0x00, 0x90, 0x91, 0xf1, 0xe3, 0xc1, 0x0f, 0xf8, 0x0c, 0x6c, 0x3e, 0xf8, 0x03, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x98, 0x99, 0xf1,
-        0xe7, 0xc3, 0x1f, 0xf8, 0x0c, 0x6c, 0xfc, 0xf8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0x31, 0xf8, 0xfb, 0x33, 0xe6, 0xc7, 0x18, 0x18, 0x0c,
-        0xfe, 0xc0, 0x18, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xf0, 0x33, 0xf8, 0xfb, 0x33, 0x66, 0xc6, 0x18, 0x18, 0x0c, 0xfe, 0xc6, 0x18, 0x03, 0xf0,
-        0x00, 0x00, 0x03, 0x90, 0x67, 0x8c, 0x79, 0xcc, 0x18, 0x03, 0x30, 0x37, 0x80, 0x81, 0xf1,
-        0x67, 0xcc, 0x1f, 0x18, 0xfc, 0xc6, 0xfe, 0x18, 0x03, 0x10, 0x00, 0x00, 0x02, 0x18, 0x94,
-        0x52, 0x42, 0x2a, 0xa5, 0x04, 0x30, 0x36, 0x80, 0x81, 0xe1, 0x63, 0xdc, 0x0f, 0x18, 0xfc,
-        0xc3, 0x7d, 0x18, 0x03, 0x10, 0x5c, 0x32, 0x02, 0x10, 0x84, 0x5a, 0x42, 0x0a, 0xa5, 0x06,
-        0x70, 0x3c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70,
-        0x52, 0x42, 0x02, 0x10, 0x42, 0x96, 0x21, 0x89, 0x98, 0x05, 0x60, 0x38, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x52, 0x72, 0x02, 0x10, 0x22,
-        0x52, 0x22, 0x49, 0xa4, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x10, 0x5c, 0x4a, 0x02, 0x10, 0x12, 0x52, 0x22, 0x2f, 0xa4, 0x04,
-        0xe0, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0,
-        0x90, 0x73, 0x0c, 0x38, 0xf2, 0x8c, 0x21, 0xe8, 0x19, 0x03, 0xf0, 0x3f, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x30, 0x33, 0x00, 0xe0, 0xc3, 0xc3, 0xd8, 0x0f, 0xf8, 0xc1, 0xe1, 0x78, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x33, 0x00, 0xe0, 0xe7,
-        0xc7, 0xdc, 0x1f, 0xf8, 0xc3, 0xe1, 0x78, 0x00, 0x18, 0x63, 0x18, 0x78, 0x80, 0x18, 0x60,
-        0x0c, 0x30, 0x06, 0x18, 0x03, 0xf0, 0x3f, 0x00, 0x60, 0x66, 0xc6, 0xcc, 0x18, 0x18, 0x63,
-        0xe3, 0x69, 0x00, 0xa4, 0x94, 0x14, 0x40, 0xc0, 0x24, 0x90, 0x12, 0x48, 0x09, 0xa4, 0x04,
-        0xe0, 0x1f, 0x00, 0x00, 0x06, 0xc6, 0xc6, 0x18, 0x18, 0x63, 0xe3, 0x69, 0x00, 0xa0, 0x86,
-        0x14, 0x40, 0x80, 0x20, 0x80, 0x1a, 0x41, 0x89, 0xb4, 0x06, 0x00, 0x00, 0x00, 0x80, 0x07,
-        0xc7, 0xc3, 0x0f, 0xf8, 0x63, 0x63, 0x6d, 0x00, 0x90, 0x45, 0xd2, 0x23, 0x8f, 0x10, 0x40,
-        0x16, 0x20, 0x06, 0xac, 0x05, 0x00, 0x00, 0x00, 0x80, 0x87, 0xc3, 0xc7, 0x1f, 0xf8, 0x61,
-        0x63, 0x6f, 0x00, 0x88, 0x24, 0x12, 0x20, 0x80, 0x20, 0x20, 0x12, 0x40, 0x09, 0xa4, 0x04,
-        0x30, 0x3f, 0x00, 0x00, 0xc6, 0xc1, 0xcf, 0x18, 0x98, 0xf1, 0x67, 0x67, 0x00, 0x84, 0x14,
-        0x1e, 0x20, 0x80, 0x24, 0x10, 0x12, 0x48, 0x09, 0xa4, 0x04, 0xb0, 0x3f, 0x00, 0x60, 0x66,
-        0xc0, 0xcc, 0x18, 0x98, 0xf1, 0x67, 0x66, 0x00, 0x3c, 0xf3, 0x10, 0x20, 0xc0, 0x19, 0xf0,
-        0x0c, 0x31, 0x86, 0x18, 0x03, 0xb0, 0x31, 0x00, 0xe0, 0xe7, 0xc7, 0xd8, 0x1f, 0x98, 0x33,
-        0x66, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xb0, 0x31, 0x00, 0xe0, 0xe3, 0xc7, 0xf8, 0x0f, 0x18, 0x1b, 0x6e, 0x60, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3b, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x1b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xf0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x82,
-        0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x7f, 0xfc, 0xfd, 0xf8, 0xf3, 0xff, 0x03,
-        0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x67, 0xf3, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0xf0, 0xff, 0xfd, 0xfd, 0xfe, 0xf7, 0xff, 0x03, 0xf0, 0x3f, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x88, 0xaa, 0xaa, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xff, 0xfd,
-        0xfd, 0xff, 0xf7, 0xff, 0x03, 0xf0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0xd5,
-        0xdd, 0x3c, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0xf3, 0xf9, 0x7c, 0x3f, 0x77, 0xbf, 0x03,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x2a, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0xe0, 0xf3, 0xf9, 0x7c, 0x7f, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00,
-        0x00, 0x00, 0x50, 0x54, 0x77, 0xff, 0x3f, 0x07, 0x00, 0x00, 0x00, 0x00, 0xe0, 0xf3, 0xf9,
-        0x7c, 0xff, 0x01, 0x3f, 0x00, 0xe0, 0x1c, 0xf8, 0x01, 0x00, 0x00, 0x00, 0xa0, 0xaa, 0xaa,
-        0xaa, 0xaa, 0x02, 0x00, 0x00, 0x00, 0x00, 0xe0, 0xff, 0xf9, 0x7c, 0xfe, 0x07, 0x3f, 0x00,
-        0xf0, 0x3c, 0xe0, 0x1f, 0x00, 0x00, 0x00, 0x50, 0x55, 0xd5, 0xdd, 0xfd, 0x07, 0x00, 0x20,
-        0x00, 0x00, 0xe0, 0xff, 0xf8, 0x7c, 0xfc, 0x0f, 0x3f, 0x00, 0x30, 0x30, 0x00, 0x1c, 0x00,
-        0x00, 0x00, 0xa0, 0xaa, 0xaa, 0xaa, 0xaa, 0x02, 0x00, 0x30, 0x00, 0x00, 0xe0, 0xf3, 0xf9,
-        0x7c, 0xf8, 0x0f, 0x3f, 0x00, 0x30, 0x30, 0x00, 0x1f, 0x00, 0x01, 0x00, 0x51, 0x75, 0x77,
-        0xf7, 0xff, 0xc7, 0x00, 0x3c, 0x04, 0x00, 0xe0, 0xf3, 0xf9, 0x7c, 0xc0, 0x0f, 0x3f, 0x00,
-        0x30, 0x30, 0xf8, 0x0f, 0x00, 0x00, 0x80, 0x88, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x0e,
-        0x04, 0x00, 0xe0, 0xf3, 0xf9, 0x7c, 0xcf, 0x0f, 0x3f, 0x00, 0xf0, 0x3f, 0xf8, 0x00, 0x00,
-        0x04, 0x00, 0x55, 0x55, 0x55, 0xdd, 0xdd, 0xff, 0x00, 0x1f, 0x0c, 0x00, 0xf0, 0xf7, 0xf3,
-        0x3f, 0xff, 0x8f, 0x7f, 0x00, 0xe0, 0x1f, 0x08, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa,
-        0xaa, 0xaa, 0xba, 0x00, 0x1f, 0x0e, 0x00, 0xf0, 0xf7, 0xf3, 0x3f, 0xff, 0x87, 0x7f, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x10, 0x14, 0x00, 0x55, 0x55, 0x77, 0xf7, 0xff, 0xff, 0x80, 0x1f,
-        0x0f, 0x00, 0xf0, 0xf7, 0xc3, 0x0f, 0xfc, 0x81, 0x7f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x80, 0x88, 0xaa, 0xaa, 0xaa, 0xaa, 0xea, 0x8e, 0x1e, 0x0f, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01, 0x54, 0x54, 0x54, 0x55, 0x55, 0xd5,
-        0xdd, 0xfd, 0xff, 0x9f, 0xdf, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x80, 0x01, 0x00, 0x20, 0xa0, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xba, 0x8b, 0xff,
-        0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01, 0x54,
-        0x55, 0x50, 0x55, 0x75, 0x77, 0xf7, 0xff, 0xff, 0x8f, 0xff, 0x03, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x80, 0x88, 0xaa, 0xaa,
-        0xaa, 0xaa, 0xaa, 0x06, 0xee, 0x03, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03,
-        0xf0, 0x3f, 0x00, 0x00, 0x40, 0x15, 0x50, 0x55, 0x55, 0x55, 0xdd, 0xdd, 0xff, 0x07, 0xff,
-        0x01, 0x00, 0x0c, 0xfc, 0xf0, 0x01, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x70, 0x0e, 0x00,
-        0x00, 0x22, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x3b, 0xfe, 0x00, 0x00, 0x0c, 0xfc, 0xf8,
-        0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x78, 0x1e, 0x40, 0x15, 0x55, 0x55, 0x55, 0x77,
-        0x77, 0xff, 0xff, 0xff, 0x3e, 0x00, 0xe0, 0xfd, 0x0c, 0x18, 0x3b, 0x03, 0x3f, 0x3e, 0x03,
-        0x00, 0x03, 0x18, 0x18, 0x00, 0x00, 0x80, 0x88, 0xa8, 0xbc, 0xaa, 0xab, 0xaa, 0x6a, 0x0e,
-        0x00, 0xf0, 0xfd, 0x7c, 0x18, 0xb3, 0x01, 0x3f, 0x3f, 0x03, 0xf0, 0x3f, 0x18, 0x18, 0x00,
-        0x14, 0x54, 0x55, 0x55, 0xf8, 0x1d, 0xff, 0xff, 0x7f, 0x0f, 0x00, 0x30, 0x8c, 0xfd, 0xf8,
-        0xe1, 0x39, 0x63, 0x33, 0x03, 0xf0, 0x3f, 0x18, 0x18, 0x00, 0x20, 0x22, 0xa2, 0x2a, 0xb8,
-        0x1a, 0xaf, 0xba, 0xbb, 0x07, 0x00, 0x30, 0x8c, 0xcd, 0xf8, 0xe3, 0x38, 0x63, 0x33, 0x03,
-        0x00, 0x00, 0xf8, 0x1f, 0x00, 0x50, 0x54, 0x55, 0xd5, 0x78, 0x17, 0xff, 0xff, 0xff, 0x03,
-        0x00, 0x30, 0x8c, 0xc1, 0x18, 0xe3, 0x00, 0x63, 0x33, 0x03, 0x00, 0x00, 0xf0, 0x0f, 0x00,
-        0x00, 0x80, 0x88, 0xaa, 0xfc, 0xba, 0xaf, 0xaa, 0xee, 0x00, 0x00, 0x30, 0x8c, 0xcd, 0x18,
-        0xe3, 0x01, 0x63, 0x33, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x55, 0x55, 0xd5, 0x7f,
-        0xfd, 0xdf, 0xff, 0xff, 0x00, 0x00, 0xf0, 0x8d, 0xfd, 0xf8, 0xb3, 0x01, 0x63, 0x3f, 0x03,
-        0xe0, 0x1c, 0x30, 0x0f, 0x00, 0x00, 0x22, 0xaa, 0xaa, 0xbf, 0xfa, 0xaf, 0xaa, 0xbb, 0x03,
-        0x00, 0xe0, 0x8d, 0xf9, 0xf0, 0x39, 0x03, 0x63, 0x3e, 0x03, 0xf0, 0x3c, 0x38, 0x1f, 0x00,
-        0x40, 0x55, 0x55, 0x55, 0x7f, 0xf7, 0xff, 0xff, 0xff, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x98, 0x19, 0x00, 0x00, 0x80, 0x88, 0xa8, 0xbe,
-        0xea, 0xab, 0xaa, 0xea, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x30, 0x30, 0x98, 0x19, 0x00, 0x50, 0x7d, 0x55, 0x55, 0xd5, 0xdd, 0xfd, 0xff, 0xb7, 0x0f,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x98, 0x19, 0x00,
-        0x20, 0xd0, 0xa3, 0xaa, 0xaa, 0xba, 0xaa, 0xfa, 0xb2, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f, 0xf8, 0x1d, 0x00, 0x50, 0x31, 0x5f, 0x55, 0xf5,
-        0xff, 0xff, 0xdf, 0x98, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0xf0, 0x1f, 0xf0, 0x0c, 0x00, 0x00, 0x60, 0xf0, 0xa8, 0xaa, 0xaf, 0xaa, 0x1b, 0xc8, 0x02,
-        0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x07, 0x00, 0x00, 0x00,
-        0x40, 0x25, 0x40, 0x7f, 0x55, 0xdd, 0x7d, 0x03, 0xcc, 0x03, 0x00, 0x00, 0x30, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0xc2, 0x00, 0xd0, 0xff,
-        0xff, 0x17, 0x00, 0x84, 0x01, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x38, 0xf8, 0x1f, 0x00, 0x00, 0x85, 0x00, 0x00, 0x80, 0x02, 0x00, 0x00, 0xe2, 0x00,
-        0x9e, 0xdf, 0xf1, 0xc3, 0xe7, 0xf3, 0x19, 0x83, 0xe7, 0x03, 0x00, 0x3f, 0xf8, 0x1f, 0x00,
-        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe2, 0x00, 0xbf, 0xff, 0xf3, 0xe7, 0xe7,
-        0xf3, 0x31, 0x83, 0xe7, 0x03, 0xf0, 0x07, 0x00, 0x00, 0x00, 0x00, 0x14, 0x01, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x61, 0x00, 0xb3, 0x39, 0x33, 0x66, 0x66, 0x30, 0x30, 0x9f, 0x61, 0x00,
-        0x30, 0x00, 0xf0, 0x18, 0x00, 0x00, 0x20, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00,
-        0xbf, 0x19, 0x33, 0x66, 0xe6, 0x70, 0xb0, 0x9d, 0xe1, 0x00, 0xf0, 0x07, 0xf8, 0x1f, 0x00,
-        0x00, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0xbf, 0x19, 0x33, 0x66, 0x86,
-        0xc3, 0xa1, 0x81, 0x81, 0x03, 0x00, 0x3f, 0x98, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x08, 0x00, 0x83, 0x19, 0x33, 0x66, 0x06, 0x83, 0xe1, 0x81, 0x01, 0x03,
-        0x00, 0x30, 0x98, 0x01, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00,
-        0xbf, 0x19, 0xf3, 0xe7, 0xe7, 0xf3, 0xe1, 0x81, 0xe1, 0x03, 0xf0, 0x3f, 0x98, 0x01, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xbe, 0x19, 0xf3, 0xc3, 0xe7,
-        0xf3, 0xc1, 0x80, 0xe1, 0x03, 0xf0, 0x07, 0xf8, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x00, 0x00, 0x00,
-        0x30, 0x00, 0xf8, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00,
-    ];
+// 图片资源, 由 build.rs 把 assets/*.png 转换成XBM格式并按行做PackBits压缩
+// 修改图片只需要替换PNG文件, 尺寸会在编译时检查
+// 绘制时逐行解压, 只需要一行大小的栈缓冲区
+
+/// 一行XBM数据的最大字节数, build/images.rs 会检查图片宽度不超过 MAX_STRIDE * 8
+pub const MAX_STRIDE: usize = 64;
+
+/// 压缩后的1位XBM图片
+/// 解压后每行按字节对齐, 每个字节低位在左, 1为黑色
+/// 每行单独压缩, 压缩包不会跨行: 头字节n为0..=127时后面n+1个字节原样复制,
+/// 为-127..=-1时后面一个字节重复1-n次, -128忽略
+pub struct Image {
+    pub width: u16,
+    pub height: u16,
+    pub data: &'static [u8],
 }
+
+impl Image {
+    pub const fn stride(&self) -> usize {
+        (self.width as usize).div_ceil(8)
+    }
+
+    /// 按从上到下的顺序逐行解压, 每行调用一次 f(行号, 行数据)
+    pub fn for_each_row(&self, mut f: impl FnMut(u16, &[u8])) {
+        let stride = self.stride();
+        let mut row = [0u8; MAX_STRIDE];
+        let mut src = self.data;
+        for y in 0..self.height {
+            let mut pos = 0;
+            while pos < stride {
+                let header = src[0] as i8;
+                if header >= 0 {
+                    let n = header as usize + 1;
+                    row[pos..pos + n].copy_from_slice(&src[1..1 + n]);
+                    src = &src[1 + n..];
+                    pos += n;
+                } else if header != -128 {
+                    let n = (1 - header as i16) as usize;
+                    row[pos..pos + n].fill(src[1]);
+                    src = &src[2..];
+                    pos += n;
+                } else {
+                    src = &src[1..];
+                }
+            }
+            f(y, &row[..stride]);
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/images.rs"));
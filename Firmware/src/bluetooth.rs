@@ -1,16 +1,18 @@
+use crate::board::BOARD;
 use crate::config;
 use crate::regs;
 use crate::rtc;
 use crate::rtc::Time;
+use crate::storage::PersistentState;
 use ch58x::ch58x;
 use ch58x_hal::ble::ffi::*;
 use ch58x_hal::ble::gap::*;
 use ch58x_hal::ble::MacAddress;
+use ch58x_hal::gpio::AnyPin;
 use ch58x_hal::gpio::Input;
-use ch58x_hal::gpio::Pin;
 use ch58x_hal::gpio::Pull;
 use ch58x_hal::with_safe_access;
-use ch58x_hal::{ble, peripherals, println};
+use ch58x_hal::{ble, println};
 use chrono::prelude::*;
 use embassy_sync::channel::Channel;
 use embassy_time::{Duration, Timer};
@@ -35,6 +37,21 @@ pub struct AdStructure<'d> {
     ad_data: &'d [u8],
 }
 
+// 十六进制字符串转整数, 非十六进制字符按0处理, 溢出时结果为0
+fn parse_hex(hex: &[u8]) -> i32 {
+    let mut result = 0i32;
+    for d in hex {
+        let digit = match d {
+            b'0'..=b'9' => *d as i32 - b'0' as i32,
+            b'A'..=b'F' => 10 + *d as i32 - b'A' as i32,
+            b'a'..=b'f' => 10 + *d as i32 - b'a' as i32,
+            _ => 0, // 如果字符不是十六进制字符，则默认为 0
+        };
+        result = result.checked_mul(16).unwrap_or(0) + digit;
+    }
+    result
+}
+
 impl<'d> AdStructure<'d> {
     pub fn new(data: &'d [u8]) -> Option<Self> {
         if data.len() < 3 {
@@ -64,16 +81,7 @@ impl<'d> AdStructure<'d> {
             // 'F' + 时间戳的16进制字符串 + 'R'
             match self.ad_data {
                 [b'F', hex @ .., b'R'] => {
-                    let mut result = 0i32;
-                    for d in hex {
-                        let digit = match d {
-                            b'0'..=b'9' => *d as i32 - b'0' as i32,
-                            b'A'..=b'F' => 10 + *d as i32 - b'A' as i32,
-                            b'a'..=b'f' => 10 + *d as i32 - b'a' as i32,
-                            _ => 0, // 如果字符不是十六进制字符，则默认为 0
-                        };
-                        result = result.checked_mul(16).unwrap_or(0) + digit;
-                    }
+                    let result = parse_hex(hex);
                     let dt: DateTime<Utc> = DateTime::from_timestamp(result.into(), 0)?;
                     println!("adv time: {:?}", dt);
                     // 打印时间
@@ -95,6 +103,29 @@ impl<'d> AdStructure<'d> {
         }
         None
     }
+
+    // 'P' + 日期格式 + 'R', 格式见 date_format::write_date
+    pub fn parse2pattern(&self) -> Option<&'d [u8]> {
+        if self.ad_type == 0x09 || self.ad_type == 0x08 {
+            if let [b'P', pattern @ .., b'R'] = self.ad_data {
+                println!("adv date pattern: {:?}", core::str::from_utf8(pattern));
+                return Some(pattern);
+            }
+        }
+        None
+    }
+
+    // 'S' + 设置项 + 值的16进制字符串 + 'R', 设置项见 settings.rs
+    pub fn parse2setting(&self) -> Option<(u8, u32)> {
+        if self.ad_type == 0x09 || self.ad_type == 0x08 {
+            if let [b'S', key, hex @ .., b'R'] = self.ad_data {
+                let value = parse_hex(hex) as u32;
+                println!("adv setting: {} = {}", *key as char, value);
+                return Some((*key, value));
+            }
+        }
+        None
+    }
 }
 
 unsafe extern "C" fn observer_event_callback(event: &gapRoleEvent_t) {
@@ -136,21 +167,37 @@ unsafe extern "C" fn observer_event_callback(event: &gapRoleEvent_t) {
             if data.len() == 0 {
                 return;
             }
+            // 同一个广播包里可以同时带时间和多个设置, 全部处理完再重启
+            // 只有设置确实被修改时才重启, 附近名称碰巧符合格式的设备不会让装置反复重启
+            // 读取flash较慢, 解析出设置之后才读取保存的状态
+            let mut received = false;
+            let mut state: Option<PersistentState> = None;
             while i < data.len() {
                 let might_ad = AdStructure::new(&data[i..1 + (data[i] as usize + i)]);
                 if let Some(ad) = might_ad {
                     if let Some(time) = ad.parse2time() {
                         let rtc = rtc::take();
                         rtc.set_time(time).unwrap();
-                        // ch58x_hal::delay_ms(50u16);
-                        unsafe {
-                            ch58x_hal::reset();
-                        }
-                        break;
-                    };
+                        received = true;
+                    } else if let Some((key, value)) = ad.parse2setting() {
+                        let state = state.get_or_insert_with(PersistentState::load);
+                        received |= state.settings.set(key, value);
+                    } else if let Some(pattern) = ad.parse2pattern() {
+                        let state = state.get_or_insert_with(PersistentState::load);
+                        received |= state.settings.set_date_pattern(pattern);
+                    }
                 };
                 i += (data[i] + 1) as usize;
             }
+            if received {
+                if let Some(state) = &state {
+                    state.save();
+                }
+                // ch58x_hal::delay_ms(50u16);
+                unsafe {
+                    ch58x_hal::reset();
+                }
+            }
         }
         _ => {
             println!("unknown event opcode: {}", event.gap.opcode);
@@ -185,7 +232,7 @@ pub async fn observer_task() {
 
 #[embassy_executor::task]
 pub async fn observer_timeout_task() {
-    let wake_up_btn = Input::new(unsafe { peripherals::PB4::steal().degrade() }, Pull::Up);
+    let wake_up_btn = Input::new(unsafe { AnyPin::steal(BOARD.wake_up_btn) }, Pull::Up);
     let rtc = rtc::take();
     let pair_begin = rtc.now().unwrap();
     let mut delta: u8 = 0;
@@ -0,0 +1,115 @@
+// 板级配置: 屏幕驱动IC, 旋转方向, SPI引脚以及控制引脚
+// 换板子或者换外壳朝向时, 只需要切换cargo feature, 不用改代码
+use crate::display::{DriverIC, Rotation};
+
+#[cfg(not(any(
+    feature = "panel_ssd1607",
+    feature = "panel_ssd1681",
+    feature = "panel_il3820"
+)))]
+compile_error!("需要选择一个屏幕驱动: panel_ssd1607/panel_ssd1681/panel_il3820");
+
+#[cfg(any(
+    all(feature = "panel_ssd1607", feature = "panel_ssd1681"),
+    all(feature = "panel_ssd1607", feature = "panel_il3820"),
+    all(feature = "panel_ssd1681", feature = "panel_il3820")
+))]
+compile_error!("panel_ssd1607/panel_ssd1681/panel_il3820 只能选择一个");
+
+#[cfg(all(feature = "three_color", not(feature = "panel_ssd1681")))]
+compile_error!("three_color 仅支持 panel_ssd1681");
+
+#[cfg(all(feature = "three_color", feature = "page_buffer"))]
+compile_error!("three_color 需要完整的红色平面, 不能与 page_buffer 同时使用");
+
+#[cfg(all(feature = "grayscale", not(feature = "panel_ssd1681")))]
+compile_error!("grayscale 仅支持 panel_ssd1681");
+
+#[cfg(all(
+    feature = "grayscale",
+    any(feature = "three_color", feature = "page_buffer")
+))]
+compile_error!("grayscale 需要完整的低位平面, 不能与 three_color/page_buffer 同时使用");
+
+#[cfg(not(any(
+    feature = "rotation_0",
+    feature = "rotation_90",
+    feature = "rotation_180",
+    feature = "rotation_270"
+)))]
+compile_error!("需要选择一个屏幕旋转方向: rotation_0/rotation_90/rotation_180/rotation_270");
+
+/// 引脚编号, 计算方式与 `SoftwareI2C` 相同: `pin + port * 32`
+pub type PinId = u8;
+
+pub const fn pa(pin: u8) -> PinId {
+    pin
+}
+
+pub const fn pb(pin: u8) -> PinId {
+    32 + pin
+}
+
+/// SPI0 的引脚映射, 由 R16_PIN_ALTERNATE 决定, 只有两种可选
+#[derive(Clone, Copy)]
+pub enum SpiPins {
+    /// SCK=PA13, MOSI=PA14
+    Default,
+    /// SCK=PB13, MOSI=PB14
+    Remapped,
+}
+
+pub struct Board {
+    pub driver_ic: DriverIC,
+    pub rotation: Rotation,
+    pub spi: SpiPins,
+    pub dc: PinId,
+    // active low
+    pub res: PinId,
+    pub cs: PinId,
+    // SGM6603 使能脚, 用于彻底断开墨水屏供电
+    pub en: PinId,
+    pub busy: PinId,
+    pub wake_up_btn: PinId,
+    pub i2c_sda: PinId,
+    pub i2c_scl: PinId,
+}
+
+const fn driver_ic() -> DriverIC {
+    if cfg!(feature = "panel_ssd1681") {
+        DriverIC::SSD1681
+    } else if cfg!(feature = "panel_il3820") {
+        DriverIC::IL3820
+    } else {
+        DriverIC::SSD1607
+    }
+}
+
+const fn rotation() -> Rotation {
+    if cfg!(feature = "rotation_0") {
+        Rotation::R0
+    } else if cfg!(feature = "rotation_90") {
+        Rotation::R90
+    } else if cfg!(feature = "rotation_180") {
+        Rotation::R180
+    } else {
+        Rotation::R270
+    }
+}
+
+/// 今天是周五吗 v2 主板
+pub const FRIDAY_INK: Board = Board {
+    driver_ic: driver_ic(),
+    rotation: rotation(),
+    spi: SpiPins::Remapped,
+    dc: pa(15),
+    res: pa(5),
+    cs: pb(12),
+    en: pb(7),
+    busy: pa(4),
+    wake_up_btn: pb(4),
+    i2c_sda: pa(12),
+    i2c_scl: pa(13),
+};
+
+pub const BOARD: Board = FRIDAY_INK;
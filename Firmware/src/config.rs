@@ -1,3 +1,5 @@
+use crate::locale::Language;
+
 // 按钮消抖样式, 单位ms
 pub const DEBOUNCE_TIME: u64 = 50;
 // 配对模式超时重启时间, 单位ms
@@ -6,3 +8,22 @@ pub const PAIR_MODE_TIME_OUT: u8 = 20;
 // 闹钟, 用于每天定时刷新
 pub const ALARM_HOUR: u8 = 0;
 pub const ALARM_MINUTE: u8 = 0;
+
+// 等待墨水屏BUSY释放的超时时间, 单位ms
+pub const BUSY_TIMEOUT_MS: u32 = 5000;
+// 刷新失败后的重试次数
+pub const DISPLAY_RETRIES: u8 = 1;
+
+// 每刷新多少次做一次黑白全刷清屏, 0表示不启用
+pub const CLEAN_EVERY_UPDATES: u16 = 30;
+// 每隔多少天做一次黑白全刷清屏, 0表示不启用
+pub const CLEAN_EVERY_DAYS: u16 = 7;
+
+// 在屏幕右上角显示芯片温度
+pub const SHOW_TEMPERATURE: bool = false;
+
+// 没有从App设置过语言时使用的语言
+pub const DEFAULT_LANGUAGE: Language = Language::Chinese;
+
+// 截图的格式, false为PBM, true为XBM
+pub const SCREENSHOT_XBM: bool = false;
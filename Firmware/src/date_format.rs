@@ -0,0 +1,311 @@
+// 日期格式化, 格式与chrono的strftime相同, 只实现日期相关的部分
+// 直接写入 fmt::Write, 不需要分配内存, 也不依赖chrono的格式化代码
+// 星期和月份名称来自当前语言的 Strings
+use core::fmt::{self, Write};
+
+use crate::locale::{Language, Strings};
+use crate::text::TextBuf;
+use crate::time::Time;
+
+/// 可以从App选择的日期格式, 下标即设置值
+pub const DATE_FORMATS: &[&str] = &[
+    // ISO 8601
+    "%Y-%m-%d",
+    // ISO 8601 加周数
+    "%Y-%m-%d W%V",
+    // 美式
+    "%m/%d/%Y",
+    "%a, %b %-d %Y",
+    // 欧式
+    "%d.%m.%Y",
+    "%a %-d %b %Y",
+    // 中日韩
+    "%Y年%m月%d日",
+    "%Y年%-m月%-d日 %a",
+];
+
+/// 自定义格式最多占用的字节数(UTF-8), 保存在设置中
+pub const PATTERN_LEN: usize = 32;
+/// 屏幕上日期文字的最大字节数
+pub const LABEL_LEN: usize = 64;
+
+// 检查格式时使用的日期, 英文的星期和月份名称在这一天都是最长的
+const SAMPLE: Time = Time {
+    year: 30,
+    month: 9,
+    day: 27,
+    hour: 0,
+    minute: 0,
+    second: 0,
+    week: 2,
+};
+
+/// 从App设置的日期格式, 不足 PATTERN_LEN 的部分补0, 全为0表示没有设置
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Pattern {
+    bytes: [u8; PATTERN_LEN],
+}
+
+impl Pattern {
+    pub const EMPTY: Self = Self {
+        bytes: [0; PATTERN_LEN],
+    };
+
+    /// 格式过长, 包含'\0', 或者在任意一种语言下 write_date 出错(包括超出 LABEL_LEN)时返回 None
+    pub fn new(pattern: &str) -> Option<Self> {
+        if pattern.len() > PATTERN_LEN || pattern.contains('\0') {
+            return None;
+        }
+        let mut index = 0;
+        while let Some(language) = Language::from_index(index) {
+            let mut label = TextBuf::<LABEL_LEN>::new();
+            write_date(&mut label, pattern, &SAMPLE, language.strings()).ok()?;
+            index += 1;
+        }
+        let mut bytes = [0; PATTERN_LEN];
+        bytes[..pattern.len()].copy_from_slice(pattern.as_bytes());
+        Some(Self { bytes })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes[0] == 0
+    }
+
+    // 从flash读出的数据损坏时返回空字符串
+    pub fn as_str(&self) -> &str {
+        let len = self
+            .bytes
+            .iter()
+            .position(|b| *b == 0)
+            .unwrap_or(PATTERN_LEN);
+        core::str::from_utf8(&self.bytes[..len]).unwrap_or("")
+    }
+}
+
+const DAYS_BEFORE_MONTH: [u16; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+fn is_leap(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+// 一年中的第几天, 从1开始
+fn day_of_year(year: u16, month: u8, day: u8) -> u16 {
+    let index = (month as usize).clamp(1, 12) - 1;
+    let leap = (month > 2 && is_leap(year)) as u16;
+    DAYS_BEFORE_MONTH[index] + leap + day as u16
+}
+
+// 一年的ISO周数, 1月1日是周四或者闰年1月1日是周三时有53周
+fn iso_weeks_in_year(year: u16) -> u16 {
+    let p = |y: u16| (y + y / 4 - y / 100 + y / 400) % 7;
+    if p(year) == 4 || p(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+/// ISO 8601 的 (年, 周数), 年初和年末的几天可能属于相邻的年份
+/// week 与 Time::week 相同, 0为周一
+pub fn iso_week(year: u16, month: u8, day: u8, week: u8) -> (u16, u8) {
+    let ordinal = day_of_year(year, month, day) as i16;
+    let number = (ordinal - (week as i16 + 1) + 10) / 7;
+    if number < 1 {
+        (year - 1, iso_weeks_in_year(year - 1) as u8)
+    } else if number as u16 > iso_weeks_in_year(year) {
+        (year + 1, 1)
+    } else {
+        (year, number as u8)
+    }
+}
+
+/// 按格式写出日期, 支持的格式:
+/// %Y %C %y %G %g  年, 世纪, 两位年, ISO周所在的年
+/// %m %b %B %h     月
+/// %d %e %j        日, 空格补齐的日, 一年中的第几天
+/// %a %A %u %w     星期, %u 周一为1, %w 周日为0
+/// %V              ISO周数
+/// %F %D %x        %Y-%m-%d, %m/%d/%y, %m/%d/%y
+/// %% %n %t        %, 换行, 制表符
+/// 数字格式前可以加 '-' 去掉补齐, '_' 用空格补齐, '0' 用0补齐, 不支持的格式返回错误
+pub fn write_date(
+    w: &mut impl Write,
+    pattern: &str,
+    time: &Time,
+    strings: &Strings,
+) -> fmt::Result {
+    let year = time.year as u16 + 1970;
+    let (iso_year, iso_number) = iso_week(year, time.month, time.day, time.week);
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            w.write_char(c)?;
+            continue;
+        }
+        let mut spec = chars.next().ok_or(fmt::Error)?;
+        let pad = match spec {
+            '-' | '_' | '0' => {
+                let pad = Some(spec);
+                spec = chars.next().ok_or(fmt::Error)?;
+                pad
+            }
+            _ => None,
+        };
+        let number = |w: &mut _, value: u16, width: usize, fill: char| {
+            write_number(w, value, width, pad.unwrap_or(fill))
+        };
+        match spec {
+            'Y' => number(w, year, 4, '0')?,
+            'C' => number(w, year / 100, 2, '0')?,
+            'y' => number(w, year % 100, 2, '0')?,
+            'G' => number(w, iso_year, 4, '0')?,
+            'g' => number(w, iso_year % 100, 2, '0')?,
+            'm' => number(w, time.month as u16, 2, '0')?,
+            'b' | 'h' => w.write_str(strings.month_short(time.month))?,
+            'B' => w.write_str(strings.month(time.month))?,
+            'd' => number(w, time.day as u16, 2, '0')?,
+            'e' => number(w, time.day as u16, 2, ' ')?,
+            'j' => number(w, day_of_year(year, time.month, time.day), 3, '0')?,
+            'a' => w.write_str(strings.weekday_short(time.week))?,
+            'A' => w.write_str(strings.weekday(time.week))?,
+            'u' => number(w, time.week as u16 % 7 + 1, 1, '0')?,
+            'w' => number(w, (time.week as u16 + 1) % 7, 1, '0')?,
+            'V' => number(w, iso_number as u16, 2, '0')?,
+            'F' => write_date(w, "%Y-%m-%d", time, strings)?,
+            'D' | 'x' => write_date(w, "%m/%d/%y", time, strings)?,
+            '%' => w.write_char('%')?,
+            'n' => w.write_char('\n')?,
+            't' => w.write_char('\t')?,
+            _ => return Err(fmt::Error),
+        }
+    }
+    Ok(())
+}
+
+// fill 为 '-' 时不补齐
+fn write_number(w: &mut impl Write, value: u16, width: usize, fill: char) -> fmt::Result {
+    match fill {
+        '-' => write!(w, "{}", value),
+        ' ' | '_' => write!(w, "{:>width$}", value, width = width),
+        _ => write!(w, "{:0width$}", value, width = width),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::{CHINESE, ENGLISH, JAPANESE};
+    use chrono::{Datelike, NaiveDate};
+
+    // 所有支持的格式, 数字格式再加上三种补齐方式
+    const SPECIFIERS: &str = "%Y %C %y %G %g %m %b %B %h %d %e %j %a %A %u %w %V %F %D %x %% %n %t";
+    const NUMERIC: &[char] = &['Y', 'C', 'y', 'G', 'g', 'm', 'd', 'e', 'j', 'u', 'w', 'V'];
+
+    fn time(date: NaiveDate) -> Time {
+        Time {
+            year: (date.year() - 1970) as u8,
+            month: date.month() as u8,
+            day: date.day() as u8,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            week: date.weekday().num_days_from_monday() as u8,
+        }
+    }
+
+    fn format(date: NaiveDate, pattern: &str) -> String {
+        let mut out = String::new();
+        write_date(&mut out, pattern, &time(date), &ENGLISH).unwrap();
+        out
+    }
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn patterns() -> Vec<String> {
+        let mut patterns: Vec<String> = DATE_FORMATS.iter().map(|p| p.to_string()).collect();
+        patterns.push(SPECIFIERS.to_string());
+        for pad in ['-', '_', '0'] {
+            let padded: Vec<String> = NUMERIC.iter().map(|c| format!("%{}{}", pad, c)).collect();
+            patterns.push(padded.join(" "));
+        }
+        patterns
+    }
+
+    // Time::year 能表示的每一天都与chrono的结果相同, 星期和月份名称使用英文
+    #[test]
+    fn matches_chrono() {
+        let patterns = patterns();
+        let mut date = ymd(1970, 1, 1);
+        while date.year() < 1970 + 256 {
+            for pattern in &patterns {
+                assert_eq!(
+                    format(date, pattern),
+                    date.format(pattern).to_string(),
+                    "{} {}",
+                    date,
+                    pattern
+                );
+            }
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    // 年初和年末的ISO周属于相邻的年份
+    #[test]
+    fn iso_week_year_boundary() {
+        for (date, expected) in [
+            (ymd(2020, 12, 31), "2020-W53"),
+            (ymd(2021, 1, 3), "2020-W53"),
+            (ymd(2021, 1, 4), "2021-W01"),
+            (ymd(2026, 12, 31), "2026-W53"),
+            (ymd(2024, 12, 30), "2025-W01"),
+        ] {
+            assert_eq!(format(date, "%G-W%V"), expected);
+            assert_eq!(date.format("%G-W%V").to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn leap_year_day_of_year() {
+        for (date, expected) in [
+            (ymd(2000, 3, 1), "061"),
+            (ymd(2000, 12, 31), "366"),
+            (ymd(2023, 12, 31), "365"),
+            (ymd(2024, 2, 29), "060"),
+            (ymd(2100, 3, 1), "060"),
+            (ymd(2100, 12, 31), "365"),
+        ] {
+            assert_eq!(format(date, "%j"), expected, "{}", date);
+        }
+    }
+
+    #[test]
+    fn unsupported_specifier() {
+        let date = time(ymd(2024, 5, 17));
+        for pattern in ["%H", "%Q", "%-", "100%", "%_Z"] {
+            assert!(write_date(&mut String::new(), pattern, &date, &ENGLISH).is_err());
+        }
+    }
+
+    #[test]
+    fn pattern_validation() {
+        let defaults = [
+            CHINESE.date_format,
+            ENGLISH.date_format,
+            JAPANESE.date_format,
+        ];
+        for pattern in DATE_FORMATS.iter().copied().chain(defaults) {
+            assert_eq!(Pattern::new(pattern).unwrap().as_str(), pattern);
+        }
+        assert!(Pattern::new("").unwrap().is_empty());
+        // 格式错误, 超出 PATTERN_LEN, 或者写出的文字超出 LABEL_LEN
+        assert!(Pattern::new("%Y-%m-%Q").is_none());
+        assert!(Pattern::new("%Y\0").is_none());
+        assert!(Pattern::new(&"-".repeat(PATTERN_LEN + 1)).is_none());
+        assert!(Pattern::new("%A %B %A %B %A %B %A %B").is_none());
+        assert!(Pattern::new("%A %B %A %B").is_some());
+    }
+}
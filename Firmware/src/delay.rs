@@ -0,0 +1,89 @@
+// 基于SysTick计数器的延时, 每次延时按当前实际的系统时钟换算
+// power.rs 切换主频之后延时依旧准确
+use ch58x::ch58x;
+
+use crate::power;
+
+// QingKe V4A SysTick: CTLR@+0, 64位的 CNT@+4, CMP@+0xC, CNTFG@+0x14, 通过PAC访问
+// 这里只用 CNT 的低32位: 两次读取之间不会超过一轮, 按差值累加即可, 也不会读到高低两半不一致的值
+const STK_STE: u32 = 1 << 0; // 计数器使能
+const STK_STCLK: u32 = 1 << 2; // 时钟源: 1=HCLK, 0=HCLK/8
+const STK_MODE: u32 = 1 << 4; // 计数方向: 1=向下计数, 0=向上计数
+
+fn systick() -> ch58x::SYSTICK {
+    unsafe { ch58x::SYSTICK::steal() }
+}
+
+fn ctlr() -> u32 {
+    systick().ctlr().read().bits()
+}
+
+fn count() -> u32 {
+    systick().cnt().read().bits() as u32
+}
+
+fn systick_hz(ctlr: u32) -> u32 {
+    let hclk = power::sys_clock_hz();
+    if ctlr & STK_STCLK != 0 {
+        hclk
+    } else {
+        hclk / 8
+    }
+}
+
+// SysTick当前计数, 统一换算为向上计数, 用于测量耗时
+pub fn ticks() -> u32 {
+    let cnt = count();
+    if ctlr() & STK_MODE != 0 {
+        u32::MAX - cnt
+    } else {
+        cnt
+    }
+}
+
+// 按当前系统时钟把SysTick计数换算为微秒
+pub fn ticks_to_us(ticks: u32) -> u32 {
+    let hz = systick_hz(ctlr());
+    (ticks as u64 * 1_000_000 / hz as u64) as u32
+}
+
+fn delay_ticks(ticks: u64) {
+    let ctlr = ctlr();
+    // embassy的时间驱动通常已经打开了SysTick, 没有打开时作为自由运行的计数器打开
+    if ctlr & STK_STE == 0 {
+        systick()
+            .ctlr()
+            .modify(|r, w| unsafe { w.bits(r.bits() | STK_STE) });
+    }
+    let count_down = ctlr & STK_MODE != 0;
+    let mut elapsed: u64 = 0;
+    let mut last = count();
+    while elapsed < ticks {
+        let now = count();
+        let delta = if count_down {
+            last.wrapping_sub(now)
+        } else {
+            now.wrapping_sub(last)
+        };
+        elapsed += delta as u64;
+        last = now;
+    }
+}
+
+pub fn delay_ns(ns: u32) {
+    let hz = systick_hz(ctlr());
+    // 至少等待一个计数, 保证不短于要求的时间
+    let ticks = (ns as u64 * hz as u64).div_ceil(1_000_000_000).max(1);
+    delay_ticks(ticks);
+}
+
+pub fn delay_us(us: u32) {
+    let hz = systick_hz(ctlr());
+    let ticks = (us as u64 * hz as u64).div_ceil(1_000_000).max(1);
+    delay_ticks(ticks);
+}
+
+pub fn delay_ms(ms: u32) {
+    let hz = systick_hz(ctlr());
+    delay_ticks(ms as u64 * hz as u64 / 1000);
+}
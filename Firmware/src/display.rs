@@ -1,67 +1,311 @@
 extern crate u8g2_rs;
 use core::cell::RefCell;
-use core::ffi::{c_char, c_void};
+use core::ffi::c_void;
 use core::fmt::Write;
 use core::slice;
 
-use crate::assets;
+use crate::assets::{self, Image};
+use crate::board::{Board, PinId, SpiPins};
+use crate::config;
+use crate::date_format::{self, Pattern};
+use crate::delay;
+use crate::fonts;
+use crate::gpio::{self, Edge};
+use crate::layout::{Layout, Rect};
+use crate::locale::Language;
+use crate::panel::{Compensation, PanelProfile, PinLevel, PinPull, PowerStep};
+use crate::power;
 use crate::rtc::Time;
-use ch58x_hal::gpio::{AnyPin, Input, Level, Output, OutputDrive, Pin, Pull};
+#[cfg(feature = "screenshot")]
+use crate::screenshot;
+#[cfg(feature = "spi_dma")]
+use crate::spi_dma;
+use crate::text::{self, HAlign, TextBuf, VAlign, WordWrap};
+use crate::theme::{self, Theme};
+use crate::waveform;
+use ch58x_hal::gpio::{AnyPin, Input, Level, Output, OutputDrive, Pull};
 use ch58x_hal::println;
 use ch58x_hal::spi::{BitOrder, Spi};
 use ch58x_hal::{peripherals, prelude::*};
+use embassy_time::{with_timeout, Duration};
 use embedded_hal_02::spi::Polarity;
-use qingke::riscv;
 use u8g2_rs::*;
 
 extern crate core;
 
 use core::fmt::{self};
 
+// u8g2每次只发送几个字节(一个tile为8字节), 先攒起来再一次性用DMA发出去
+#[cfg(feature = "spi_dma")]
+const SPI_BATCH_SIZE: usize = 256;
+// 太短的数据直接写FIFO更快
+#[cfg(feature = "spi_dma")]
+const SPI_DMA_MIN_LEN: usize = 16;
+// 攒数据的缓冲区, DMA直接从这里发送
+#[cfg(feature = "spi_dma")]
+static mut SPI_BATCH: [u8; SPI_BATCH_SIZE] = [0; SPI_BATCH_SIZE];
+
+// 只在u8g2的字节回调中使用, 不会重入; 经过裸指针取引用, 避免直接引用 static mut
+#[cfg(feature = "spi_dma")]
+fn spi_batch() -> &'static mut [u8; SPI_BATCH_SIZE] {
+    unsafe { &mut *core::ptr::addr_of_mut!(SPI_BATCH) }
+}
+
 // 在u8g2中,这些产量是宏定义的,没有被bindgen转换,所以需要手动补充一下
 pub const U8X8_MSG_GPIO_CS: u32 = 73;
 pub const U8X8_MSG_GPIO_DC: u32 = 74;
 pub const U8X8_MSG_GPIO_RESET: u32 = 75;
 
-pub enum DriverIC {
-    SSD1607,
-    SSD1681,
+pub use crate::panel::DriverIC;
+
+type SetupFn = unsafe extern "C" fn(*mut u8g2_t, *const u8g2_cb_t, u8x8_msg_cb, u8x8_msg_cb);
+
+// 全缓冲模式需要 宽x高/8 字节的RAM, 分页模式只需要一行tile
+#[cfg(not(feature = "page_buffer"))]
+fn u8g2_setup(driver_ic: DriverIC) -> SetupFn {
+    match driver_ic {
+        DriverIC::SSD1607 => u8g2_Setup_ssd1607_gd_200x200_f,
+        DriverIC::SSD1681 => u8g2_Setup_ssd1681_zjy_200x200_f,
+        DriverIC::IL3820 => u8g2_Setup_il3820_v2_296x128_f,
+    }
 }
 
-pub struct StringWriter {
-    buffer: [u8; 32],
-    pos: usize,
+#[cfg(feature = "page_buffer")]
+fn u8g2_setup(driver_ic: DriverIC) -> SetupFn {
+    match driver_ic {
+        DriverIC::SSD1607 => u8g2_Setup_ssd1607_gd_200x200_1,
+        DriverIC::SSD1681 => u8g2_Setup_ssd1681_zjy_200x200_1,
+        DriverIC::IL3820 => u8g2_Setup_il3820_v2_296x128_1,
+    }
 }
 
-impl StringWriter {
-    pub fn new() -> StringWriter {
-        StringWriter {
-            buffer: [0; 32],
-            pos: 0,
-        }
+fn level(level: PinLevel) -> Level {
+    match level {
+        PinLevel::Low => Level::Low,
+        PinLevel::High => Level::High,
     }
+}
+
+fn pull(pull: PinPull) -> Pull {
+    match pull {
+        PinPull::Floating => Pull::None,
+        PinPull::Up => Pull::Up,
+        PinPull::Down => Pull::Down,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisplayError {
+    // 刷新过程中BUSY超时未释放
+    BusyTimeout,
+    // SPI写入失败
+    Spi,
+    // 初始化时BUSY一直未释放, 屏幕可能没有接好
+    NotResponding,
+}
+
+// 屏幕上可以显示的画面
+pub enum Screen {
+    // 时间同步模式
+    Pair,
+    // 今天是周五吗
+    Friday(Time),
+}
 
-    pub fn as_str(&self) -> &str {
-        core::str::from_utf8(&self.buffer[..self.pos]).unwrap()
+const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
+
+fn fnv1a(mut hash: u32, data: &[u8]) -> u32 {
+    for byte in data {
+        hash ^= *byte as u32;
+        hash = hash.wrapping_mul(FNV_PRIME);
     }
+    hash
 }
 
-impl Write for StringWriter {
-    fn write_str(&mut self, s: &str) -> fmt::Result {
-        let bytes = s.as_bytes();
-        let len = bytes.len();
+// 界面使用的字体, 除了中文字体都是u8g2自带的字体, 没用到的会被链接器丢弃
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Font {
+    // 中文文字, 16px, 编译时按用到的文字裁剪
+    Text,
+    // 英文标签, 12px
+    Latin,
+    // 状态等小号文字, 6x10 只有ASCII
+    Small,
+    // 以下只有数字和少量符号(-.:/等)
+    NumberSmall,
+    NumberMedium,
+    // 大号数字, 例如整屏显示的日期
+    NumberLarge,
+}
 
-        if self.pos + len > self.buffer.len() {
-            return Err(fmt::Error);
+impl Font {
+    fn data(self) -> &'static [u8] {
+        unsafe {
+            match self {
+                Font::Text => fonts::UNIFONT_16,
+                Font::Latin => &u8g2_font_helvR12_tr,
+                Font::Small => &u8g2_font_6x10_tr,
+                Font::NumberSmall => &u8g2_font_logisoso16_tn,
+                Font::NumberMedium => &u8g2_font_logisoso32_tn,
+                Font::NumberLarge => &u8g2_font_logisoso62_tn,
+            }
         }
+    }
+}
 
-        self.buffer[self.pos..self.pos + len].copy_from_slice(bytes);
-        self.pos += len;
+#[derive(Clone, Copy, PartialEq)]
+pub enum Color {
+    White,
+    Black,
+    // 仅三色屏(SSD1681 BWR)可用, 写入RED RAM(0x26)
+    #[cfg(feature = "three_color")]
+    Red,
+}
 
-        Ok(())
+// 第二个平面, 与u8g2的全缓冲区布局一致(vertical_top_lsb), 写入RAM 0x26
+// 三色屏存放红色, 灰度模式存放灰度的低位
+#[cfg(any(feature = "three_color", feature = "grayscale"))]
+static mut SECOND_PLANE: [u8; 5000] = [0; 5000];
+
+// 只在主循环里访问, 同一时间只有一个引用; 和SPI_BATCH一样经过裸指针取引用
+#[cfg(any(feature = "three_color", feature = "grayscale"))]
+fn second_plane() -> &'static mut [u8; 5000] {
+    unsafe { &mut *core::ptr::addr_of_mut!(SECOND_PLANE) }
+}
+
+// 4级灰度, 每个像素2位: 高位在u8g2自己的缓冲区, 低位在SECOND_PLANE
+#[cfg(feature = "grayscale")]
+#[derive(Clone, Copy, PartialEq)]
+pub enum Gray {
+    White = 0,
+    Light = 1,
+    Dark = 2,
+    Black = 3,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Rotation {
+    R0,
+    R90,
+    R180,
+    R270,
+}
+
+impl Rotation {
+    // 0/1/2/3 对应 0/90/180/270 度
+    pub fn from_index(index: u8) -> Option<Self> {
+        match index {
+            0 => Some(Rotation::R0),
+            1 => Some(Rotation::R90),
+            2 => Some(Rotation::R180),
+            3 => Some(Rotation::R270),
+            _ => None,
+        }
     }
+
+    pub fn degrees(self) -> u16 {
+        self as u16 * 90
+    }
+
+    fn u8g2_cb(&self, mirror: bool) -> &'static u8g2_cb_t {
+        unsafe {
+            match (self, mirror) {
+                (Rotation::R0, false) => &u8g2_rs::u8g2_cb_r0,
+                (Rotation::R90, false) => &u8g2_rs::u8g2_cb_r1,
+                (Rotation::R180, false) => &u8g2_rs::u8g2_cb_r2,
+                (Rotation::R270, false) => &u8g2_rs::u8g2_cb_r3,
+                (Rotation::R0, true) => &MIRROR_R0,
+                (Rotation::R90, true) => &MIRROR_R1,
+                (Rotation::R180, true) => &MIRROR_R2,
+                (Rotation::R270, true) => &MIRROR_R3,
+            }
+        }
+    }
+}
+
+// 左右镜像: 在旋转之后的坐标系里翻转x, 再交给对应方向的绘制函数
+// u8g2在调用之前已经按 update_page_win_mirror 算出的窗口裁剪过, 翻转之后正好落在当前页内
+unsafe extern "C" fn draw_l90_mirror<const R: u8>(
+    u8g2: *mut u8g2_t,
+    x: u8g2_uint_t,
+    y: u8g2_uint_t,
+    len: u8g2_uint_t,
+    dir: u8,
+) {
+    let width = (*u8g2).width;
+    let x = match dir {
+        // 水平线, 起点变成原来的终点
+        0 => width.saturating_sub(x + len),
+        _ => width.saturating_sub(x + 1),
+    };
+    match R {
+        0 => u8g2_draw_l90_r0(u8g2, x, y, len, dir),
+        1 => u8g2_draw_l90_r1(u8g2, x, y, len, dir),
+        2 => u8g2_draw_l90_r2(u8g2, x, y, len, dir),
+        _ => u8g2_draw_l90_r3(u8g2, x, y, len, dir),
+    }
+}
+
+// 当前页在镜像之前的坐标系里对应的窗口, u8g2按这个窗口裁剪之后再交给 draw_l90_mirror
+// 分页模式下R1/R3的页是x方向的一段, 不翻转窗口的话镜像后的线会画到缓冲区外面
+// 裁剪窗口本来就是镜像前的坐标, 先按不裁剪算出页窗口, 翻转后再与裁剪窗口求交
+unsafe extern "C" fn update_page_win_mirror<const R: u8>(u8g2: *mut u8g2_t) {
+    let u = &mut *u8g2;
+    let clip = (u.clip_x0, u.clip_y0, u.clip_x1, u.clip_y1);
+    u.clip_x0 = 0;
+    u.clip_y0 = 0;
+    u.clip_x1 = u8g2_uint_t::MAX;
+    u.clip_y1 = u8g2_uint_t::MAX;
+    match R {
+        0 => u8g2_update_page_win_r0(u8g2),
+        1 => u8g2_update_page_win_r1(u8g2),
+        2 => u8g2_update_page_win_r2(u8g2),
+        _ => u8g2_update_page_win_r3(u8g2),
+    }
+    let u = &mut *u8g2;
+    (u.clip_x0, u.clip_y0, u.clip_x1, u.clip_y1) = clip;
+    let width = u.width;
+    (u.user_x0, u.user_x1) = (
+        width.saturating_sub(u.user_x1),
+        width.saturating_sub(u.user_x0),
+    );
+    if u.clip_x0 >= u.user_x1
+        || u.clip_x1 <= u.user_x0
+        || u.clip_y0 >= u.user_y1
+        || u.clip_y1 <= u.user_y0
+    {
+        u.is_page_clip_window_intersection = 0;
+        return;
+    }
+    u.is_page_clip_window_intersection = 1;
+    u.user_x0 = u.user_x0.max(u.clip_x0);
+    u.user_y0 = u.user_y0.max(u.clip_y0);
+    u.user_x1 = u.user_x1.min(u.clip_x1);
+    u.user_y1 = u.user_y1.min(u.clip_y1);
 }
 
+static MIRROR_R0: u8g2_cb_t = u8g2_cb_t {
+    update_dimension: Some(u8g2_update_dimension_r0),
+    update_page_win: Some(update_page_win_mirror::<0>),
+    draw_l90: Some(draw_l90_mirror::<0>),
+};
+static MIRROR_R1: u8g2_cb_t = u8g2_cb_t {
+    update_dimension: Some(u8g2_update_dimension_r1),
+    update_page_win: Some(update_page_win_mirror::<1>),
+    draw_l90: Some(draw_l90_mirror::<1>),
+};
+static MIRROR_R2: u8g2_cb_t = u8g2_cb_t {
+    update_dimension: Some(u8g2_update_dimension_r2),
+    update_page_win: Some(update_page_win_mirror::<2>),
+    draw_l90: Some(draw_l90_mirror::<2>),
+};
+static MIRROR_R3: u8g2_cb_t = u8g2_cb_t {
+    update_dimension: Some(u8g2_update_dimension_r3),
+    update_page_win: Some(update_page_win_mirror::<3>),
+    draw_l90: Some(draw_l90_mirror::<3>),
+};
+
 pub unsafe extern "C" fn u8x8_byte_ch582f_hw_spi(
     u8x8: *mut u8x8_t,
     msg: u8,
@@ -89,10 +333,11 @@ pub unsafe extern "C" fn u8x8_byte_ch582f_hw_spi(
                 return 0;
             }
             let data = slice::from_raw_parts(arg_ptr as *const u8, arg_int.into());
-            let _ = display.spi_host.blocking_write(data);
+            display.spi_write(data);
         }
         U8X8_MSG_BYTE_INIT => {}
         U8X8_MSG_BYTE_SET_DC => {
+            display.spi_flush();
             if arg_int == 0 {
                 display.dc.set_low();
             } else {
@@ -100,9 +345,12 @@ pub unsafe extern "C" fn u8x8_byte_ch582f_hw_spi(
             }
         }
         U8X8_MSG_BYTE_START_TRANSFER => {
+            // 上一次刷新还没结束时先等它完成, 屏幕在BUSY期间不接收命令
+            display.finish_refresh();
             display.cs.set_low();
         }
         U8X8_MSG_BYTE_END_TRANSFER => {
+            display.spi_flush();
             display.cs.set_high();
         }
         _ => {}
@@ -132,36 +380,22 @@ pub unsafe extern "C" fn u8x8_gpio_and_delay_ch582f(
         // 根据你的需要处理不同的消息
         U8X8_MSG_GPIO_AND_DELAY_INIT => {}
         U8X8_MSG_DELAY_NANO => {
-            for _ in 0..=20 {
-                riscv::asm::nop();
-            }
+            delay::delay_ns(arg_int.into());
         }
         U8X8_MSG_DELAY_100NANO => {
-            for _ in 0..=2000 {
-                riscv::asm::nop();
-            }
+            delay::delay_ns(arg_int as u32 * 100);
         }
         U8X8_MSG_DELAY_10MICRO => {
-            ch58x_hal::delay_ms(1);
+            delay::delay_us(arg_int as u32 * 10);
         }
         U8X8_MSG_DELAY_MILLI => {
-            // TODO 理论上说1681需要借助Busy脚去检测状态
-            let busy = Input::new(peripherals::PA4::steal().degrade(), Pull::Up);
-            let mut max_delay: u16 = arg_int.into();
-            let busy_level = match display.driver_ic {
-                DriverIC::SSD1607 => Level::High,
-                DriverIC::SSD1681 => Level::High,
-            };
-            while busy.get_level() == busy_level {
-                println!("wait for busy low");
-                if max_delay > 0 {
-                    ch58x_hal::delay_ms(max_delay);
-                    max_delay -= 1;
-                } else {
-                    break;
-                }
+            // 复位脉宽需要精确延时, 其余的毫秒延时都是u8g2在等屏幕刷新, 交给BUSY决定
+            if display.in_reset {
+                let ms = display.profile.reset_pulse_ms.unwrap_or(arg_int);
+                delay::delay_ms(ms.into());
+            } else {
+                display.wait_busy();
             }
-            // ch58x_hal::delay_ms(arg_int.into());
         }
         U8X8_MSG_GPIO_CS => {
             if arg_int == 0 {
@@ -178,6 +412,7 @@ pub unsafe extern "C" fn u8x8_gpio_and_delay_ch582f(
             }
         }
         U8X8_MSG_GPIO_RESET => {
+            display.in_reset = arg_int == 0;
             if arg_int == 0 {
                 display.res.set_low();
             } else {
@@ -196,68 +431,99 @@ pub struct Display<'d> {
     pub res: Output<'d, AnyPin>,
     pub cs: Output<'d, AnyPin>,
     pub en: Output<'d, AnyPin>,
-    driver_ic: DriverIC,
+    busy: Input<'d, AnyPin>,
+    busy_pin: PinId,
+    profile: &'static PanelProfile,
+    // RES脚处于拉低状态
+    in_reset: bool,
+    // 发送画面时不在回调中等待刷新结束, 记到 refresh_pending 中交给 wait_refresh
+    defer_busy: bool,
+    refresh_pending: bool,
+    // 刷新前测得的温度, 用于选择刷新波形
+    temperature: Option<i8>,
+    language: Language,
+    date_format: Pattern,
+    theme: &'static Theme,
+    invert: bool,
+    // 旋转角度和镜像, 写进截图里, 由 tools/screenshot.py 还原成看到的方向
+    #[cfg(feature = "screenshot")]
+    orientation: (u16, bool),
+    // 刷新时是否输出截图, 由设置打开
+    #[cfg(feature = "screenshot")]
+    screenshot: bool,
+    // 全缓冲模式下 fingerprint 画好的一帧还在缓冲区中, show 直接发送, 不再重画
+    #[cfg(not(feature = "page_buffer"))]
+    prepared: bool,
+    // SPI_BATCH 中已经攒下的字节数
+    #[cfg(feature = "spi_dma")]
+    spi_batch_len: usize,
+    // 本次刷新SPI发送的字节数和耗时(SysTick计数), 用于衡量上传速度
+    spi_bytes: u32,
+    spi_ticks: u32,
+    // 回调中发生的第一个错误, 由init/send_buffer/set_power_save取出返回
+    error: Option<DisplayError>,
+    #[cfg(any(feature = "three_color", feature = "grayscale"))]
+    main_plane: *mut u8,
+    // 灰度模式下当前正在绘制的位, 1为高位平面, 0为低位平面
+    #[cfg(feature = "grayscale")]
+    gray_bit: u8,
 }
 
 impl<'d> Display<'d> {
-    pub fn new(driver_ic: DriverIC, byte_cb: u8x8_msg_cb, gpio_and_delay_cb: u8x8_msg_cb) -> Self {
+    pub fn new(board: &Board, byte_cb: u8x8_msg_cb, gpio_and_delay_cb: u8x8_msg_cb) -> Self {
+        let profile = board.driver_ic.profile();
         let mut u8g2: u8g2_t = unsafe { core::mem::zeroed() };
         unsafe {
-            let rotation = &u8g2_rs::u8g2_cb_r3;
-            match driver_ic {
-                DriverIC::SSD1607 => {
-                    u8g2_Setup_ssd1607_gd_200x200_f(
-                        &mut u8g2,
-                        rotation,
-                        byte_cb,
-                        gpio_and_delay_cb,
-                    );
-                }
-                DriverIC::SSD1681 => {
-                    u8g2_Setup_ssd1681_zjy_200x200_f(
-                        &mut u8g2,
-                        rotation,
-                        byte_cb,
-                        gpio_and_delay_cb,
-                    );
-                }
-            }
+            u8g2_setup(board.driver_ic)(
+                &mut u8g2,
+                board.rotation.u8g2_cb(false),
+                byte_cb,
+                gpio_and_delay_cb,
+            );
         }
 
+        #[cfg(any(feature = "three_color", feature = "grayscale"))]
+        let main_plane = u8g2.tile_buf_ptr;
+
         let mut spi_config = ch58x_hal::spi::Config::default();
         spi_config.frequency = 20.MHz();
         spi_config.bit_order = BitOrder::MsbFirst;
         spi_config.clock_polarity = Polarity::IdleLow;
         let spi0 = unsafe { peripherals::SPI0::steal() };
-        let sck = unsafe { peripherals::PB13::steal() };
-        let mosi = unsafe { peripherals::PB14::steal() };
-        let spi_host: Spi<peripherals::SPI0> = Spi::new_txonly(spi0, sck, mosi, spi_config);
+        let spi_host: Spi<peripherals::SPI0> = match board.spi {
+            SpiPins::Default => {
+                let sck = unsafe { peripherals::PA13::steal() };
+                let mosi = unsafe { peripherals::PA14::steal() };
+                Spi::new_txonly(spi0, sck, mosi, spi_config)
+            }
+            SpiPins::Remapped => {
+                let sck = unsafe { peripherals::PB13::steal() };
+                let mosi = unsafe { peripherals::PB14::steal() };
+                Spi::new_txonly(spi0, sck, mosi, spi_config)
+            }
+        };
         let dc = Output::new(
-            unsafe { peripherals::PA15::steal() },
+            unsafe { AnyPin::steal(board.dc) },
             Level::High,
             OutputDrive::_5mA,
-        )
-        .degrade();
+        );
         // active low
         let res = Output::new(
-            unsafe { peripherals::PA5::steal() },
+            unsafe { AnyPin::steal(board.res) },
             Level::High,
             OutputDrive::_5mA,
-        )
-        .degrade();
+        );
         // SPI MODE_0, clk idle low, data valid on rising edge
         let cs = Output::new(
-            unsafe { peripherals::PB12::steal() },
+            unsafe { AnyPin::steal(board.cs) },
             Level::Low,
             OutputDrive::_5mA,
-        )
-        .degrade();
+        );
         let en = Output::new(
-            unsafe { peripherals::PB7::steal() },
+            unsafe { AnyPin::steal(board.en) },
             Level::Low,
             OutputDrive::_5mA,
-        )
-        .degrade();
+        );
         Self {
             en,
             u8g2: RefCell::new(u8g2),
@@ -265,14 +531,232 @@ impl<'d> Display<'d> {
             dc,
             res,
             cs,
-            driver_ic,
+            busy: Input::new(
+                unsafe { AnyPin::steal(board.busy) },
+                pull(profile.busy_pull),
+            ),
+            busy_pin: board.busy,
+            profile,
+            in_reset: false,
+            defer_busy: false,
+            refresh_pending: false,
+            temperature: None,
+            language: config::DEFAULT_LANGUAGE,
+            date_format: Pattern::new(config::DEFAULT_LANGUAGE.strings().date_format).unwrap(),
+            theme: &theme::DEFAULT,
+            invert: false,
+            #[cfg(feature = "screenshot")]
+            orientation: (board.rotation.degrees(), false),
+            #[cfg(feature = "screenshot")]
+            screenshot: false,
+            #[cfg(not(feature = "page_buffer"))]
+            prepared: false,
+            #[cfg(feature = "spi_dma")]
+            spi_batch_len: 0,
+            spi_bytes: 0,
+            spi_ticks: 0,
+            error: None,
+            #[cfg(any(feature = "three_color", feature = "grayscale"))]
+            main_plane,
+            #[cfg(feature = "grayscale")]
+            gray_bit: 1,
         }
     }
 
-    pub fn init(&mut self) {
-        self.en.set_high();
+    pub fn init(&mut self) -> Result<(), DisplayError> {
+        self.finish_refresh();
+        self.set_dcdc(true);
+        self.error = None;
         self.u8g2.borrow_mut().u8x8.user_ptr = self as *mut _ as *mut c_void;
         self.init_display();
+        self.load_waveform();
+        // 初始化阶段BUSY就不释放, 说明屏幕没有响应
+        self.take_error().map_err(|e| match e {
+            DisplayError::BusyTimeout => DisplayError::NotResponding,
+            e => e,
+        })
+    }
+
+    #[cfg(not(feature = "spi_dma"))]
+    fn spi_write(&mut self, data: &[u8]) {
+        self.spi_send(data);
+    }
+
+    #[cfg(not(feature = "spi_dma"))]
+    fn spi_flush(&mut self) {}
+
+    #[cfg(feature = "spi_dma")]
+    fn spi_write(&mut self, data: &[u8]) {
+        if self.spi_batch_len + data.len() > SPI_BATCH_SIZE {
+            self.spi_flush();
+        }
+        if data.len() > SPI_BATCH_SIZE {
+            self.spi_send(data);
+            return;
+        }
+        spi_batch()[self.spi_batch_len..self.spi_batch_len + data.len()].copy_from_slice(data);
+        self.spi_batch_len += data.len();
+    }
+
+    // DC切换和传输结束前必须把攒下的数据发完
+    #[cfg(feature = "spi_dma")]
+    fn spi_flush(&mut self) {
+        let len = self.spi_batch_len;
+        self.spi_batch_len = 0;
+        self.spi_send(&spi_batch()[..len]);
+    }
+
+    fn spi_send(&mut self, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+        let start = delay::ticks();
+        #[cfg(feature = "spi_dma")]
+        if data.len() >= SPI_DMA_MIN_LEN && spi_dma::can_dma(data) {
+            spi_dma::write(data);
+            self.spi_bytes += data.len() as u32;
+            self.spi_ticks += delay::ticks().wrapping_sub(start);
+            return;
+        }
+        if self.spi_host.blocking_write(data).is_err() {
+            self.error.get_or_insert(DisplayError::Spi);
+        }
+        self.spi_bytes += data.len() as u32;
+        self.spi_ticks += delay::ticks().wrapping_sub(start);
+    }
+
+    // 刷新之前设置当前温度, init时据此选择刷新波形
+    pub fn set_temperature(&mut self, celsius: i8) {
+        self.temperature = Some(celsius);
+    }
+
+    pub fn temperature(&self) -> Option<i8> {
+        self.temperature
+    }
+
+    // 屏幕上文字使用的语言, 在绘制之前设置
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+    }
+
+    // 日期的格式, 格式见 date_format::write_date, 不能使用的格式会被忽略
+    pub fn set_date_format(&mut self, pattern: &str) {
+        if let Some(pattern) = Pattern::new(pattern) {
+            self.date_format = pattern;
+        }
+    }
+
+    // 结论图片, 字体和文字位置使用的主题
+    pub fn set_theme(&mut self, theme: &'static Theme) {
+        self.theme = theme;
+    }
+
+    // 反色显示, 白底黑字变成黑底白字
+    pub fn set_invert(&mut self, invert: bool) {
+        self.invert = invert;
+    }
+
+    fn load_waveform(&mut self) {
+        let Some(celsius) = self.temperature else {
+            return;
+        };
+        match self.profile.compensation {
+            Compensation::HostLut => {
+                if let Some(lut) = waveform::lut_for(celsius) {
+                    println!("load waveform for {}C", celsius);
+                    self.send_command(0x32, &lut);
+                }
+            }
+            // u8g2初始化时已选择内部温度传感器(0x18 0x80), 刷新时按面板温度加载OTP波形
+            Compensation::Internal => {}
+            Compensation::Fixed => {}
+        }
+    }
+
+    fn send_command(&mut self, cmd: u8, args: &[u8]) {
+        let mut u8g2 = self.u8g2.borrow_mut();
+        let u8x8 = &mut u8g2.u8x8;
+        unsafe {
+            u8x8_cad_StartTransfer(u8x8);
+            u8x8_cad_SendCmd(u8x8, cmd);
+            for arg in args {
+                u8x8_cad_SendArg(u8x8, *arg);
+            }
+            u8x8_cad_EndTransfer(u8x8);
+        }
+    }
+
+    fn take_error(&mut self) -> Result<(), DisplayError> {
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    // BUSY有效时的电平, 以及释放时的边沿
+    fn busy_edge(&self) -> (Level, Edge) {
+        let busy_level = level(self.profile.busy_level);
+        let release_edge = if self.profile.busy_release_falling() {
+            Edge::Falling
+        } else {
+            Edge::Rising
+        };
+        (busy_level, release_edge)
+    }
+
+    // 等待BUSY释放, 超过 config::BUSY_TIMEOUT_MS 记为超时
+    // 已经出错时不再等待, 避免每条延时消息都耗满超时时间
+    // u8g2的回调是同步的, 不能await, 这里用BUSY边沿中断的挂起位唤醒wfi
+    // 发送画面后的刷新等待由 defer_busy 推迟到 wait_refresh, 在embassy执行器中等待
+    fn wait_busy(&mut self) {
+        if self.error.is_some() {
+            return;
+        }
+        if self.defer_busy {
+            self.refresh_pending = true;
+            return;
+        }
+        let (busy_level, release_edge) = self.busy_edge();
+        if self.busy.get_level() != busy_level {
+            return;
+        }
+        gpio::enable_edge_wake(self.busy_pin, release_edge);
+        let busy = &self.busy;
+        let released =
+            power::idle_until(config::BUSY_TIMEOUT_MS, || busy.get_level() != busy_level);
+        gpio::disable_edge_wake(self.busy_pin);
+        if !released {
+            println!("wait for busy timeout");
+            self.error = Some(DisplayError::BusyTimeout);
+        }
+    }
+
+    // 没有await wait_refresh就继续和屏幕通信时, 同步等待刷新结束
+    fn finish_refresh(&mut self) {
+        if core::mem::take(&mut self.refresh_pending) {
+            let defer_busy = core::mem::take(&mut self.defer_busy);
+            self.wait_busy();
+            self.defer_busy = defer_busy;
+        }
+    }
+
+    // 等待 render 发起的刷新结束, 期间让出执行器, 由BUSY的边沿中断唤醒
+    // 刷新需要几秒, 这段时间CPU在执行器的wfi中idle
+    pub async fn wait_refresh(&mut self) -> Result<(), DisplayError> {
+        if !core::mem::take(&mut self.refresh_pending) || self.error.is_some() {
+            return self.take_error();
+        }
+        let (busy_level, release_edge) = self.busy_edge();
+        let busy = &self.busy;
+        let released = gpio::wait_for_edge(self.busy_pin, release_edge, || {
+            busy.get_level() != busy_level
+        });
+        let timeout = Duration::from_millis(config::BUSY_TIMEOUT_MS as u64);
+        if with_timeout(timeout, released).await.is_err() {
+            println!("wait for busy timeout");
+            self.error = Some(DisplayError::BusyTimeout);
+        }
+        self.take_error()
     }
 
     fn init_display(&mut self) {
@@ -283,36 +767,368 @@ impl<'d> Display<'d> {
     }
 
     //000101
-    pub fn set_power_save(&mut self, enable: bool) {
+    // 按屏幕的上下电顺序执行
+    pub fn set_power_save(&mut self, enable: bool) -> Result<(), DisplayError> {
+        self.finish_refresh();
+        let steps = if enable {
+            self.profile.power_off
+        } else {
+            self.profile.power_on
+        };
+        for step in steps {
+            self.power_step(*step);
+        }
         if enable {
-            self.en.set_low();
-            unsafe { u8x8_SetPowerSave(&mut self.u8g2.borrow_mut().u8x8, 1) };
-            self.cs.set_low();
-            match self.driver_ic {
-                DriverIC::SSD1607 => {
-                    self.res.set_low();
-                    let _ = Input::new(unsafe { peripherals::PA4::steal().degrade() }, Pull::None);
-                }
-                DriverIC::SSD1681 => {
-                    self.res.set_high();
-                    let _ = Input::new(unsafe { peripherals::PA4::steal().degrade() }, Pull::Up);
-                }
-            }
+            self.park();
         } else {
-            self.en.set_high();
-            unsafe { u8x8_SetPowerSave(&mut self.u8g2.borrow_mut().u8x8, 0) };
             self.cs.set_low();
         }
+        self.take_error()
+    }
+
+    fn power_step(&mut self, step: PowerStep) {
+        match step {
+            PowerStep::DcdcOn => self.set_dcdc(true),
+            PowerStep::DcdcOff => self.set_dcdc(false),
+            PowerStep::Wake => unsafe { u8x8_SetPowerSave(&mut self.u8g2.borrow_mut().u8x8, 0) },
+            PowerStep::Sleep => unsafe { u8x8_SetPowerSave(&mut self.u8g2.borrow_mut().u8x8, 1) },
+            PowerStep::DelayMs(ms) => delay::delay_ms(ms.into()),
+        }
+    }
+
+    // 没有DCDC的屏幕EN脚一直保持低电平
+    fn set_dcdc(&mut self, on: bool) {
+        if on && self.profile.dcdc_gating {
+            self.en.set_high();
+        } else {
+            self.en.set_low();
+        }
+    }
+
+    // 把控制引脚设置为休眠时的状态, 不与屏幕通信
+    // 跳过刷新时屏幕没有上电, 直接调用这个即可
+    pub fn park(&mut self) {
+        let sleep_pins = self.profile.sleep_pins;
+        self.set_dcdc(false);
+        self.cs.set_level(level(sleep_pins.cs));
+        self.res.set_level(level(sleep_pins.res));
+        let _ = Input::new(
+            unsafe { AnyPin::steal(self.busy_pin) },
+            pull(sleep_pins.busy),
+        );
+    }
+
+    // 打开后每次刷新都把画面从UART1输出, 见 screenshot.rs
+    #[cfg(feature = "screenshot")]
+    pub fn set_screenshot(&mut self, enable: bool) {
+        self.screenshot = enable;
+    }
+
+    // 运行时修改旋转方向和镜像, 之后的绘制和布局都按新的方向计算
+    pub fn set_orientation(&mut self, rotation: Rotation, mirror: bool) {
+        unsafe {
+            u8g2_SetDisplayRotation(&mut *self.u8g2.borrow_mut(), rotation.u8g2_cb(mirror));
+        }
+        #[cfg(feature = "screenshot")]
+        {
+            self.orientation = (rotation.degrees(), mirror);
+        }
+    }
+
+    // 旋转之后的屏幕宽度
+    pub fn width(&self) -> u16 {
+        self.u8g2.borrow().width
+    }
+
+    // 旋转之后的屏幕高度
+    pub fn height(&self) -> u16 {
+        self.u8g2.borrow().height
+    }
+
+    pub fn layout(&self) -> Layout {
+        Layout::new(self.width(), self.height()).with_params(&self.theme.layout)
     }
 
     pub fn clear_buffer(&mut self) {
+        #[cfg(not(feature = "page_buffer"))]
+        {
+            self.prepared = false;
+        }
+        #[cfg(any(feature = "three_color", feature = "grayscale"))]
+        {
+            second_plane().fill(0);
+            self.u8g2.borrow_mut().tile_buf_ptr = self.main_plane;
+        }
         unsafe {
             u8g2_ClearBuffer(&mut *self.u8g2.borrow_mut());
         }
     }
 
-    pub fn send_buffer(&mut self) {
+    // 绘制并发起刷新, draw 需要可以重复调用, 之后用 wait_refresh 等待刷新结束
+    // 全缓冲模式下只调用一次, 分页模式下每一页都会从头调用一次
+    #[cfg(not(feature = "page_buffer"))]
+    pub fn render(&mut self, draw: impl Fn(&mut Self)) -> Result<(), DisplayError> {
+        self.clear_buffer();
+        self.draw_planes(&draw);
+        self.apply_invert();
+        self.send_frame()
+    }
+
+    // 发送缓冲区中已经画好的一帧并发起刷新
+    #[cfg(not(feature = "page_buffer"))]
+    fn send_frame(&mut self) -> Result<(), DisplayError> {
+        #[cfg(feature = "screenshot")]
+        if self.screenshot {
+            let (tile_width, tile_height, _) = self.tile_size();
+            screenshot::begin(tile_width, tile_height, self.orientation);
+            screenshot::write(self.buffer(), tile_width, tile_height);
+            screenshot::end();
+        }
+        self.defer_busy = true;
+        let result = self.send_buffer();
+        self.defer_busy = false;
+        result
+    }
+
+    // 灰度模式下高低两个平面各绘制一遍, set_gray按当前平面选择绘制颜色
+    #[cfg(not(feature = "page_buffer"))]
+    fn draw_planes(&mut self, draw: &impl Fn(&mut Self)) {
+        #[cfg(feature = "grayscale")]
+        {
+            for bit in [1, 0] {
+                self.gray_bit = bit;
+                self.u8g2.borrow_mut().tile_buf_ptr = match bit {
+                    1 => self.main_plane,
+                    _ => second_plane().as_mut_ptr(),
+                };
+                self.set_draw_color(1);
+                draw(self);
+            }
+            self.gray_bit = 1;
+            self.u8g2.borrow_mut().tile_buf_ptr = self.main_plane;
+        }
+        #[cfg(not(feature = "grayscale"))]
+        draw(self);
+    }
+
+    // 反色模式下在绘制之后, 发送之前把缓冲区取反, 所有画面和图片都不需要单独处理
+    // 三色屏只取反黑白平面, 红色平面不变; 灰度屏两个平面都取反, 深灰和浅灰互换
+    fn apply_invert(&mut self) {
+        if !self.invert {
+            return;
+        }
+        #[cfg(feature = "three_color")]
+        {
+            self.u8g2.borrow_mut().tile_buf_ptr = self.main_plane;
+        }
+        self.buffer_mut().iter_mut().for_each(|b| *b = !*b);
+        #[cfg(feature = "grayscale")]
+        second_plane().iter_mut().for_each(|b| *b = !*b);
+    }
+
+    #[cfg(feature = "page_buffer")]
+    pub fn render(&mut self, draw: impl Fn(&mut Self)) -> Result<(), DisplayError> {
+        self.spi_bytes = 0;
+        self.spi_ticks = 0;
+        #[cfg(feature = "screenshot")]
+        let (tile_width, tile_height, page_height) = self.tile_size();
+        #[cfg(feature = "screenshot")]
+        if self.screenshot {
+            screenshot::begin(tile_width, tile_height, self.orientation);
+        }
+        // 最后一页发送完后u8g2发起刷新
+        self.defer_busy = true;
+        unsafe { u8g2_FirstPage(&mut *self.u8g2.borrow_mut()) };
+        loop {
+            draw(self);
+            self.apply_invert();
+            // 最后一页可能只有一部分在屏幕内
+            #[cfg(feature = "screenshot")]
+            if self.screenshot {
+                let row = self.u8g2.borrow().tile_curr_row;
+                let rows = page_height.min(tile_height - row);
+                let len = rows as usize * tile_width as usize * 8;
+                screenshot::write(&self.buffer()[..len], tile_width, rows);
+            }
+            if unsafe { u8g2_NextPage(&mut *self.u8g2.borrow_mut()) } == 0 {
+                break;
+            }
+        }
+        self.defer_busy = false;
+        #[cfg(feature = "screenshot")]
+        if self.screenshot {
+            screenshot::end();
+        }
+        self.log_upload();
+        self.take_error()
+    }
+
+    // 只绘制不刷新, 计算这一帧的哈希, 用于判断画面是否有变化
+    // 全缓冲模式下画好的缓冲区留给接下来的 show 直接发送, 中间不要再绘制其他内容;
+    // 分页模式下没有整帧的缓冲区, show 时还要再画一遍
+    pub fn fingerprint(&mut self, draw: impl Fn(&mut Self)) -> u32 {
+        #[cfg(not(feature = "page_buffer"))]
+        let hash = {
+            self.clear_buffer();
+            self.draw_planes(&draw);
+            self.apply_invert();
+            self.prepared = true;
+            let hash = fnv1a(FNV_OFFSET_BASIS, self.buffer());
+            #[cfg(any(feature = "three_color", feature = "grayscale"))]
+            let hash = fnv1a(hash, second_plane());
+            hash
+        };
+        #[cfg(feature = "page_buffer")]
+        let hash = {
+            let (tile_rows, page_rows) = {
+                let u8g2 = self.u8g2.borrow();
+                let tile_rows = unsafe { (*u8g2.u8x8.display_info).tile_height };
+                (tile_rows, u8g2.tile_buf_height)
+            };
+            let mut hash = FNV_OFFSET_BASIS;
+            let mut row = 0;
+            while row < tile_rows {
+                unsafe {
+                    let mut u8g2 = self.u8g2.borrow_mut();
+                    u8g2_SetBufferCurrTileRow(&mut *u8g2, row);
+                    u8g2_ClearBuffer(&mut *u8g2);
+                }
+                draw(self);
+                self.apply_invert();
+                hash = fnv1a(hash, self.buffer());
+                row += page_rows;
+            }
+            hash
+        };
+        hash
+    }
+
+    // 整屏的tile宽度, tile行数, 以及缓冲区的tile行数(分页模式下为一页)
+    #[cfg(feature = "screenshot")]
+    fn tile_size(&self) -> (u8, u8, u8) {
+        let u8g2 = self.u8g2.borrow();
+        let info = unsafe { &*u8g2.u8x8.display_info };
+        (info.tile_width, info.tile_height, u8g2.tile_buf_height)
+    }
+
+    // u8g2当前的缓冲区, 分页模式下为当前页
+    fn buffer(&self) -> &[u8] {
+        let u8g2 = self.u8g2.borrow();
+        let tile_width = unsafe { (*u8g2.u8x8.display_info).tile_width } as usize;
+        let len = 8 * u8g2.tile_buf_height as usize * tile_width;
+        unsafe { slice::from_raw_parts(u8g2.tile_buf_ptr, len) }
+    }
+
+    fn buffer_mut(&mut self) -> &mut [u8] {
+        let len = self.buffer().len();
+        unsafe { slice::from_raw_parts_mut(self.u8g2.borrow().tile_buf_ptr, len) }
+    }
+
+    fn log_upload(&self) {
+        println!(
+            "frame upload: {} bytes in {} us",
+            self.spi_bytes,
+            delay::ticks_to_us(self.spi_ticks)
+        );
+    }
+
+    #[cfg(not(any(feature = "page_buffer", feature = "grayscale")))]
+    pub fn send_buffer(&mut self) -> Result<(), DisplayError> {
+        self.spi_bytes = 0;
+        self.spi_ticks = 0;
+        #[cfg(feature = "three_color")]
+        {
+            self.u8g2.borrow_mut().tile_buf_ptr = self.main_plane;
+            self.write_ram(0x26, second_plane(), false);
+        }
         unsafe { u8g2_SendBuffer(&mut *self.u8g2.borrow_mut()) };
+        self.log_upload();
+        self.take_error()
+    }
+
+    // 灰度模式不能用u8g2的刷新命令(0x22 0xF7会从OTP重新加载LUT),
+    // 两个平面都自己写入, 再用寄存器中的灰度LUT刷新
+    // RAM中1为白色, 与u8g2缓冲区中1为黑色相反, 写入时取反:
+    // 白(1,1) 浅灰(1,0) 深灰(0,1) 黑(0,0)
+    #[cfg(feature = "grayscale")]
+    pub fn send_buffer(&mut self) -> Result<(), DisplayError> {
+        self.spi_bytes = 0;
+        self.spi_ticks = 0;
+        self.load_gray_lut();
+        // RAM内容不反相, 两个RAM都参与LUT选择
+        self.send_command(0x21, &[0x00, 0x80]);
+        unsafe {
+            let main_plane = slice::from_raw_parts(self.main_plane, second_plane().len());
+            self.write_ram(0x24, main_plane, true);
+            self.write_ram(0x26, second_plane(), true);
+        }
+        // 使用寄存器中的LUT, 不从OTP加载
+        self.send_command(0x22, &[0xC7]);
+        self.send_command(0x20, &[]);
+        self.wait_busy();
+        self.log_upload();
+        self.take_error()
+    }
+
+    #[cfg(feature = "grayscale")]
+    fn load_gray_lut(&mut self) {
+        let lut = &waveform::LUT_4GRAY;
+        self.send_command(0x32, &lut[..153]);
+        self.send_command(0x3F, &lut[153..154]);
+        self.send_command(0x03, &lut[154..155]);
+        self.send_command(0x04, &lut[155..158]);
+        self.send_command(0x2C, &lut[158..159]);
+    }
+
+    // 按u8g2 ssd1681驱动draw_tile的方式, 把一个平面写入指定的RAM
+    // 每个tile行对应RAM中的一个x地址(倒序), 每列一个字节, 地址计数器沿y方向递增
+    #[cfg(any(feature = "three_color", feature = "grayscale"))]
+    fn write_ram(&mut self, ram_cmd: u8, plane: &[u8], invert: bool) {
+        let mut u8g2 = self.u8g2.borrow_mut();
+        let u8x8 = &mut u8g2.u8x8;
+        let (tile_width, tile_height) = unsafe {
+            let info = &*u8x8.display_info;
+            (info.tile_width as usize, info.tile_height as usize)
+        };
+        let width = tile_width * 8;
+        unsafe {
+            u8x8_cad_StartTransfer(u8x8);
+            for page in 0..tile_height {
+                u8x8_cad_SendCmd(u8x8, 0x4e);
+                u8x8_cad_SendArg(u8x8, (tile_height - 1 - page) as u8);
+                u8x8_cad_SendCmd(u8x8, 0x4f);
+                u8x8_cad_SendArg(u8x8, 0);
+                u8x8_cad_SendArg(u8x8, 0);
+                u8x8_cad_SendCmd(u8x8, ram_cmd);
+                let row = &plane[page * width..(page + 1) * width];
+                let mut buf = [0u8; u8::MAX as usize];
+                for chunk in row.chunks(buf.len()) {
+                    for (dst, src) in buf.iter_mut().zip(chunk) {
+                        *dst = if invert { !*src } else { *src };
+                    }
+                    u8x8_cad_SendData(u8x8, chunk.len() as u8, buf.as_mut_ptr());
+                }
+            }
+            u8x8_cad_EndTransfer(u8x8);
+        }
+    }
+
+    // 切换绘制颜色, 红色绘制到SECOND_PLANE, 黑白绘制到u8g2自己的缓冲区
+    pub fn set_color(&mut self, color: Color) {
+        #[cfg(feature = "three_color")]
+        {
+            self.u8g2.borrow_mut().tile_buf_ptr = match color {
+                Color::Red => second_plane().as_mut_ptr(),
+                _ => self.main_plane,
+            };
+        }
+        self.set_draw_color(if color == Color::White { 0 } else { 1 });
+    }
+
+    // 灰度模式下的绘制颜色, 之后的绘制都使用这个灰度, 直到再次设置
+    #[cfg(feature = "grayscale")]
+    pub fn set_gray(&mut self, gray: Gray) {
+        self.set_draw_color((gray as u8 >> self.gray_bit) & 1);
     }
 
     pub fn set_font_mode(&mut self, is_transparent: u8) {
@@ -325,32 +1141,88 @@ impl<'d> Display<'d> {
         unsafe { u8g2_SetFontDirection(&mut *self.u8g2.borrow_mut(), dir) }
     }
 
-    pub fn set_font(&mut self, font: &[u8]) {
+    pub fn set_font(&mut self, font: Font) {
+        self.set_font_data(font.data());
+    }
+
+    // 直接使用u8g2格式的字体数据, 常用字体见 Font
+    pub fn set_font_data(&mut self, font: &[u8]) {
         unsafe {
             u8g2_SetFont(&mut *self.u8g2.borrow_mut(), font.as_ptr());
         }
     }
 
-    pub fn draw_utf8(&mut self, x: u16, y: u16, str_: &str) {
-        unsafe {
-            u8g2_DrawUTF8(
-                &mut *self.u8g2.borrow_mut(),
-                x,
-                y,
-                str_.as_ptr() as *const i8,
-            );
-        }
+    // 文字不需要以NUL结尾, 超长的文字分段绘制(仅支持 font direction 0), 返回绘制的宽度
+    pub fn draw_utf8(&mut self, x: u16, y: u16, str_: &str) -> u16 {
+        let mut width = 0;
+        text::for_each_c_str(str_, |s| {
+            width +=
+                unsafe { u8g2_DrawUTF8(&mut *self.u8g2.borrow_mut(), x.wrapping_add(width), y, s) };
+        });
+        width
     }
 
-    pub fn draw_str(&mut self, x: u16, y: u16, str_: &str) {
-        unsafe {
-            u8g2_DrawStr(
-                &mut *self.u8g2.borrow_mut(),
-                x,
-                y,
-                str_.as_ptr() as *const c_char,
-            );
+    // 只支持ASCII, 中文等需要使用 draw_utf8
+    pub fn draw_str(&mut self, x: u16, y: u16, str_: &str) -> u16 {
+        let mut width = 0;
+        text::for_each_c_str(str_, |s| {
+            width +=
+                unsafe { u8g2_DrawStr(&mut *self.u8g2.borrow_mut(), x.wrapping_add(width), y, s) };
+        });
+        width
+    }
+
+    // 格式化后绘制, 结果超过64字节时不绘制并返回错误
+    pub fn draw_fmt(&mut self, x: u16, y: u16, args: fmt::Arguments) -> fmt::Result {
+        let mut buf = TextBuf::<64>::new();
+        buf.write_fmt(args)?;
+        self.draw_utf8(x, y, buf.as_str());
+        Ok(())
+    }
+
+    // 当前字体下文字的宽度
+    pub fn text_width(&mut self, str_: &str) -> u16 {
+        let mut width = 0;
+        text::for_each_c_str(str_, |s| {
+            width += unsafe { u8g2_GetUTF8Width(&mut *self.u8g2.borrow_mut(), s) };
+        });
+        width
+    }
+
+    // 当前字体基线以上的高度
+    pub fn font_ascent(&self) -> i8 {
+        self.u8g2.borrow().font_ref_ascent
+    }
+
+    // 当前字体基线以下的高度, 为负数
+    pub fn font_descent(&self) -> i8 {
+        self.u8g2.borrow().font_ref_descent
+    }
+
+    // 在区域内按对齐方式绘制一行文字
+    pub fn draw_text_aligned(&mut self, area: Rect, str_: &str, h: HAlign, v: VAlign) {
+        let x = h.x(area, self.text_width(str_));
+        let y = v.baseline(area, self.font_ascent(), self.font_descent());
+        self.draw_utf8(x as u16, y as u16, str_);
+    }
+
+    // 在区域内自动换行绘制, 超出区域高度的行不绘制, 返回绘制的行数
+    pub fn draw_text_wrapped(&mut self, area: Rect, str_: &str, h: HAlign) -> u16 {
+        let (ascent, descent) = (self.font_ascent(), self.font_descent());
+        let line_height = ascent as i16 - descent as i16;
+        let mut wrap = WordWrap::new(str_, area.w);
+        let mut lines = 0;
+        let mut y = area.y + ascent as i16;
+        while y - (ascent as i16) + line_height <= area.y + area.h as i16 {
+            let Some(line) = wrap.next_line(|s| self.text_width(s)) else {
+                break;
+            };
+            let x = h.x(area, self.text_width(line));
+            self.draw_utf8(x as u16, y as u16, line);
+            lines += 1;
+            y += line_height;
         }
+        lines
     }
 
     pub fn draw_xbm(
@@ -373,6 +1245,13 @@ impl<'d> Display<'d> {
         }
     }
 
+    // 图片逐行解压后按高度为1的XBM绘制, 不需要整张图片大小的缓冲区
+    pub fn draw_image(&mut self, x: i16, y: i16, image: &Image) {
+        image.for_each_row(|row, data| {
+            self.draw_xbm(x, y + row as i16, image.width, 1, data.as_ptr());
+        });
+    }
+
     pub fn set_draw_color(&mut self, color: u8) {
         unsafe {
             u8g2_SetDrawColor(&mut *self.u8g2.borrow_mut(), color);
@@ -383,51 +1262,106 @@ impl<'d> Display<'d> {
         unsafe { u8g2_DrawBox(&mut *self.u8g2.borrow_mut(), x, y, w, h) };
     }
 
+    // 之后的绘制只在区域内生效, 直到调用 set_max_clip_window
+    pub fn set_clip_window(&mut self, rect: Rect) {
+        unsafe {
+            u8g2_SetClipWindow(
+                &mut *self.u8g2.borrow_mut(),
+                rect.x.max(0) as u16,
+                rect.y.max(0) as u16,
+                (rect.x + rect.w as i16).max(0) as u16,
+                (rect.y + rect.h as i16).max(0) as u16,
+            );
+        }
+    }
+
+    pub fn set_max_clip_window(&mut self) {
+        unsafe { u8g2_SetMaxClipWindow(&mut *self.u8g2.borrow_mut()) };
+    }
+
     pub fn draw_frame(&mut self, x: u16, y: u16, w: u16, h: u16) {
         unsafe { u8g2_DrawFrame(&mut *self.u8g2.borrow_mut(), x, y, w, h) };
     }
 
-    #[rustfmt::skip]
+    pub fn draw_screen(&mut self, screen: &Screen) {
+        match screen {
+            Screen::Pair => self.embassy_logo(),
+            Screen::Friday(time) => self.is_friday(*time),
+        }
+    }
+
+    pub async fn show(&mut self, screen: &Screen) -> Result<(), DisplayError> {
+        #[cfg(not(feature = "page_buffer"))]
+        if core::mem::take(&mut self.prepared) {
+            self.send_frame()?;
+            return self.wait_refresh().await;
+        }
+        self.render(|d| d.draw_screen(screen))?;
+        self.wait_refresh().await
+    }
+
+    // 全黑再全白刷新一次, 清除残影
+    pub async fn clean(&mut self) -> Result<(), DisplayError> {
+        let (w, h) = (self.width(), self.height());
+        self.render(|d| {
+            d.set_draw_color(1);
+            d.draw_box(0, 0, w, h);
+        })?;
+        self.wait_refresh().await?;
+        self.render(|_| {})?;
+        self.wait_refresh().await
+    }
+
     pub fn embassy_logo(&mut self) {
-        self.clear_buffer();
-        self.draw_xbm(0, 0, 200, 200, assets::img::PAIR_IMG.as_ptr());
-        self.send_buffer();
+        let image = &assets::PAIR;
+        let rect = self.layout().full_screen(image.width, image.height);
+        self.draw_image(rect.x, rect.y, image);
     }
 
     pub fn is_friday(&mut self, time: Time) {
-        self.clear_buffer();
+        let layout = self.layout();
+        let date = layout.date_label();
+        let title = layout.title_label();
+        let theme = self.theme;
+        let image = theme.verdict(time.week);
+        let verdict = layout.verdict(image.width, image.height);
         self.set_font_mode(1);
         self.set_font_direction(0);
-        unsafe {
-            self.set_font(&u8g2_font_fusion_pixel_16_mn);
-        }
-        let mut time_label = StringWriter::new();
-
-        write!(
-            &mut time_label,
-            "{:04}年{:02}月{:02}日\0",
-            time.year as u16 + 1970,
-            time.month,
-            time.day
-        )
-        .unwrap();
-        self.draw_utf8(20, 32, time_label.as_str());
-        self.draw_utf8(20, 56, "今天是周五吗\0");
+        self.set_font(theme.date_font);
+        // 灰度屏上日期用深灰, 突出标题
+        #[cfg(feature = "grayscale")]
+        self.set_gray(Gray::Dark);
+        let mut label = TextBuf::<{ date_format::LABEL_LEN }>::new();
+        let strings = self.language.strings();
+        let pattern = self.date_format.as_str();
+        if date_format::write_date(&mut label, pattern, &time, strings).is_ok() {
+            self.draw_utf8(date.x as u16, date.y as u16, label.as_str());
+        }
+        #[cfg(feature = "grayscale")]
+        self.set_gray(Gray::Black);
+        self.set_font(theme.title_font);
+        self.draw_utf8(title.x as u16, title.y as u16, strings.title);
+        if let (true, Some(celsius)) = (config::SHOW_TEMPERATURE, self.temperature) {
+            let mut label = TextBuf::<8>::new();
+            if write!(&mut label, "{}C", celsius).is_ok() {
+                self.set_font(Font::Small);
+                let status = layout.status_label();
+                let width = self.text_width(label.as_str());
+                self.draw_utf8(
+                    (status.x - width as i16) as u16,
+                    status.y as u16,
+                    label.as_str(),
+                );
+            }
+        }
+        // 三色屏上用红色突出显示周五
+        #[cfg(feature = "three_color")]
         if time.week == 4 {
-            self.draw_xbm(
-                12 - 88 + 44,
-                100,
-                176,
-                88,
-                assets::img::IMG_NOPE_ANSWER.as_ptr(),
-            );
-            self.set_draw_color(0);
-            self.draw_box(0, 100, 56, 88);
-            self.set_draw_color(1);
-        } else {
-            self.draw_xbm(12, 100, 176, 88, assets::img::IMG_NOPE_ANSWER.as_ptr());
+            self.set_color(Color::Red);
         }
-        self.send_buffer();
+        self.draw_image(verdict.x, verdict.y, image);
+        #[cfg(feature = "three_color")]
+        self.set_color(Color::Black);
     }
 
     pub fn scan_mode(&mut self) {}
@@ -0,0 +1,2 @@
+// build.rs 按 src 中用到的文字裁剪出的u8g2字体
+include!(concat!(env!("OUT_DIR"), "/fonts.rs"));
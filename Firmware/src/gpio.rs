@@ -1,3 +1,15 @@
+use core::future::poll_fn;
+use core::task::Poll;
+
+use ch58x::ch58x;
+use embassy_sync::waitqueue::AtomicWaker;
+
+use crate::board::PinId;
+use crate::power;
+
+const GPIO_A_IRQN: u8 = 17;
+const GPIO_B_IRQN: u8 = 18;
+
 pub const GPIO_PIN_0: u32 = 0x00000001;
 pub const GPIO_PIN_1: u32 = 0x00000002;
 pub const GPIO_PIN_2: u32 = 0x00000004;
@@ -22,4 +34,140 @@ pub const GPIO_PIN_20: u32 = 0x00100000;
 pub const GPIO_PIN_21: u32 = 0x00200000;
 pub const GPIO_PIN_22: u32 = 0x00400000;
 pub const GPIO_PIN_23: u32 = 0x00800000;
-pub const GPIO_PIN_ALL: u32 = 0xFFFFFFFF;
\ No newline at end of file
+pub const GPIO_PIN_ALL: u32 = 0xFFFFFFFF;
+
+#[derive(Clone, Copy)]
+pub enum Edge {
+    Rising,
+    Falling,
+}
+
+// PB22/PB23 的中断位映射到16位中断寄存器的bit8/bit9
+fn int_bit(pin: PinId) -> (bool, u16) {
+    let bit = 1u32 << (pin % 32);
+    let is_port_b = pin >= 32;
+    if is_port_b {
+        (
+            true,
+            (bit | ((bit & (GPIO_PIN_22 | GPIO_PIN_23)) >> 14)) as u16,
+        )
+    } else {
+        (false, bit as u16)
+    }
+}
+
+// 打开引脚的边沿中断, 只使能外设中断标志, 不在PFIC中使能中断
+// 中断挂起后配合 SEVONPEND 唤醒 wfi, 不需要中断服务函数
+pub fn enable_edge_wake(pin: PinId, edge: Edge) {
+    let sys = unsafe { ch58x::SYS::steal() };
+    let (is_port_b, int_bit) = int_bit(pin);
+    let io_bit = 1u32 << (pin % 32);
+    unsafe {
+        if is_port_b {
+            sys.pb_int_mode().modify(|r, w| w.bits(r.bits() | int_bit));
+            match edge {
+                Edge::Falling => sys.pb_clr().write(|w| w.bits(io_bit)),
+                Edge::Rising => sys.pb_out().modify(|r, w| w.bits(r.bits() | io_bit)),
+            }
+            sys.pb_int_if().write(|w| w.bits(int_bit));
+            sys.pb_int_en().modify(|r, w| w.bits(r.bits() | int_bit));
+        } else {
+            sys.pa_int_mode().modify(|r, w| w.bits(r.bits() | int_bit));
+            match edge {
+                Edge::Falling => sys.pa_clr().write(|w| w.bits(io_bit)),
+                Edge::Rising => sys.pa_out().modify(|r, w| w.bits(r.bits() | io_bit)),
+            }
+            sys.pa_int_if().write(|w| w.bits(int_bit));
+            sys.pa_int_en().modify(|r, w| w.bits(r.bits() | int_bit));
+        }
+    }
+}
+
+pub fn disable_edge_wake(pin: PinId) {
+    let sys = unsafe { ch58x::SYS::steal() };
+    let (is_port_b, int_bit) = int_bit(pin);
+    unsafe {
+        if is_port_b {
+            sys.pb_int_en().modify(|r, w| w.bits(r.bits() & !int_bit));
+            sys.pb_int_if().write(|w| w.bits(int_bit));
+        } else {
+            sys.pa_int_en().modify(|r, w| w.bits(r.bits() & !int_bit));
+            sys.pa_int_if().write(|w| w.bits(int_bit));
+        }
+    }
+    // 清除PFIC中的挂起位, 否则下一次挂起不会再产生唤醒事件
+    power::clear_pending(if is_port_b { GPIO_B_IRQN } else { GPIO_A_IRQN });
+}
+
+// 每个端口一个, 由端口的GPIO中断唤醒 wait_for_edge
+static EDGE_WAKERS: [AtomicWaker; 2] = [AtomicWaker::new(), AtomicWaker::new()];
+
+// 引脚中断还处于使能状态, 说明还没有触发
+fn edge_armed(is_port_b: bool, int_bit: u16) -> bool {
+    let sys = unsafe { ch58x::SYS::steal() };
+    let int_en = if is_port_b {
+        sys.pb_int_en().read().bits()
+    } else {
+        sys.pa_int_en().read().bits()
+    };
+    int_en & int_bit != 0
+}
+
+// 关闭已经触发的引脚中断并清除标志, 再唤醒等待的任务
+fn on_edge(is_port_b: bool) {
+    let sys = unsafe { ch58x::SYS::steal() };
+    unsafe {
+        if is_port_b {
+            let flags = sys.pb_int_if().read().bits();
+            sys.pb_int_en().modify(|r, w| w.bits(r.bits() & !flags));
+            sys.pb_int_if().write(|w| w.bits(flags));
+        } else {
+            let flags = sys.pa_int_if().read().bits();
+            sys.pa_int_en().modify(|r, w| w.bits(r.bits() & !flags));
+            sys.pa_int_if().write(|w| w.bits(flags));
+        }
+    }
+    EDGE_WAKERS[is_port_b as usize].wake();
+}
+
+#[qingke_rt::interrupt]
+fn GPIO_A() {
+    on_edge(false);
+}
+
+#[qingke_rt::interrupt]
+fn GPIO_B() {
+    on_edge(true);
+}
+
+// future被丢弃(比如超时)时也要关闭中断
+struct EdgeGuard {
+    pin: PinId,
+    irqn: u8,
+}
+
+impl Drop for EdgeGuard {
+    fn drop(&mut self) {
+        power::disable_irq(self.irqn);
+        disable_edge_wake(self.pin);
+    }
+}
+
+// 等待引脚的边沿中断或者 done() 返回true, 等待期间交给embassy执行器, 没有其他任务时CPU在wfi中idle
+// 先打开中断再检查 done(), 打开中断之前已经发生的边沿不会被漏掉
+pub async fn wait_for_edge(pin: PinId, edge: Edge, mut done: impl FnMut() -> bool) {
+    let (is_port_b, int_bit) = int_bit(pin);
+    let irqn = if is_port_b { GPIO_B_IRQN } else { GPIO_A_IRQN };
+    enable_edge_wake(pin, edge);
+    let _guard = EdgeGuard { pin, irqn };
+    power::enable_irq(irqn);
+    poll_fn(|cx| {
+        EDGE_WAKERS[is_port_b as usize].register(cx.waker());
+        if !edge_armed(is_port_b, int_bit) || done() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
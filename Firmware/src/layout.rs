@@ -0,0 +1,163 @@
+// 与分辨率无关的布局, 所有元素的位置都根据当前屏幕(旋转后)的宽高计算
+// 以200x200为基准设计, 横向的长条屏(如296x128)改为左文字右图片
+
+#[derive(Clone, Copy, Debug)]
+pub struct Point {
+    pub x: i16,
+    pub y: i16,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Rect {
+    pub x: i16,
+    pub y: i16,
+    pub w: u16,
+    pub h: u16,
+}
+
+impl Rect {
+    pub const fn new(x: i16, y: i16, w: u16, h: u16) -> Self {
+        Self { x, y, w, h }
+    }
+
+    /// 在当前区域内居中放置一个 w x h 的区域, 超出部分允许为负坐标(会被裁剪)
+    pub fn center(&self, w: u16, h: u16) -> Rect {
+        Rect {
+            x: self.x + (self.w as i16 - w as i16) / 2,
+            y: self.y + (self.h as i16 - h as i16) / 2,
+            w,
+            h,
+        }
+    }
+
+    /// 按比例取一个点, 比例单位为千分之一
+    pub fn at(&self, x_permille: u16, y_permille: u16) -> Point {
+        Point {
+            x: self.x + (self.w as u32 * x_permille as u32 / 1000) as i16,
+            y: self.y + (self.h as u32 * y_permille as u32 / 1000) as i16,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Orientation {
+    // 宽高接近, 如200x200
+    Square,
+    // 宽明显大于高, 如296x128, 250x122
+    Landscape,
+    // 高明显大于宽, 如128x296
+    Portrait,
+}
+
+/// 可以由主题调整的文字位置, 文字区域内的千分比坐标
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayoutParams {
+    pub date: (u16, u16),
+    pub title: (u16, u16),
+    pub landscape_date: (u16, u16),
+    pub landscape_title: (u16, u16),
+}
+
+pub const DEFAULT_PARAMS: LayoutParams = LayoutParams {
+    // 200x200 上为 (20, 32)
+    date: (100, 320),
+    // 200x200 上为 (20, 56)
+    title: (100, 560),
+    landscape_date: (30, 375),
+    landscape_title: (30, 625),
+};
+
+pub struct Layout {
+    pub screen: Rect,
+    pub orientation: Orientation,
+    pub params: &'static LayoutParams,
+}
+
+impl Layout {
+    pub fn new(width: u16, height: u16) -> Self {
+        let orientation = if width as u32 * 2 >= height as u32 * 3 {
+            Orientation::Landscape
+        } else if height as u32 * 2 >= width as u32 * 3 {
+            Orientation::Portrait
+        } else {
+            Orientation::Square
+        };
+        Self {
+            screen: Rect::new(0, 0, width, height),
+            orientation,
+            params: &DEFAULT_PARAMS,
+        }
+    }
+
+    pub fn with_params(self, params: &'static LayoutParams) -> Self {
+        Self { params, ..self }
+    }
+
+    // 文字所在的区域
+    fn text_area(&self) -> Rect {
+        let s = self.screen;
+        match self.orientation {
+            Orientation::Landscape => Rect::new(s.x, s.y, s.w * 2 / 5, s.h),
+            _ => Rect::new(s.x, s.y, s.w, s.h / 2),
+        }
+    }
+
+    // 图片所在的区域
+    fn image_area(&self) -> Rect {
+        let s = self.screen;
+        match self.orientation {
+            Orientation::Landscape => {
+                let text_w = s.w * 2 / 5;
+                Rect::new(s.x + text_w as i16, s.y, s.w - text_w, s.h)
+            }
+            _ => Rect::new(s.x, s.y + (s.h / 2) as i16, s.w, s.h - s.h / 2),
+        }
+    }
+
+    /// 日期文字的基线位置
+    pub fn date_label(&self) -> Point {
+        let (x, y) = match self.orientation {
+            Orientation::Landscape => self.params.landscape_date,
+            _ => self.params.date,
+        };
+        self.text_area().at(x, y)
+    }
+
+    /// 标题的基线位置
+    pub fn title_label(&self) -> Point {
+        let (x, y) = match self.orientation {
+            Orientation::Landscape => self.params.landscape_title,
+            _ => self.params.title,
+        };
+        self.text_area().at(x, y)
+    }
+
+    /// 温度等状态文字的右下角, 与日期同一基线, 靠右对齐
+    pub fn status_label(&self) -> Point {
+        let area = self.text_area();
+        let date = self.date_label();
+        Point {
+            x: area.x + area.w as i16 - (date.x - area.x),
+            y: date.y,
+        }
+    }
+
+    /// 结论图片的位置, 在图片区域内水平居中
+    pub fn verdict(&self, w: u16, h: u16) -> Rect {
+        let area = self.image_area();
+        match self.orientation {
+            Orientation::Landscape => area.center(w, h),
+            // 200x200 上为 (12, 100), 与文字区域紧挨
+            _ => {
+                let mut rect = area.center(w, h);
+                rect.y = area.y;
+                rect
+            }
+        }
+    }
+
+    /// 全屏图片的位置, 屏幕比图片小时居中裁剪
+    pub fn full_screen(&self, w: u16, h: u16) -> Rect {
+        self.screen.center(w, h)
+    }
+}
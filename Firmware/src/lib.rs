@@ -1,10 +1,29 @@
 #![no_std]
 pub mod assets;
 pub mod bluetooth;
+pub mod board;
 pub mod config;
+pub mod date_format;
+pub mod delay;
 pub mod display;
+pub mod fonts;
 pub mod gpio;
+pub mod layout;
+pub mod locale;
+pub mod panel;
 pub mod power;
+pub mod refresh;
 pub mod regs;
 pub mod rtc;
+#[cfg(feature = "screenshot")]
+pub mod screenshot;
+pub mod settings;
 pub mod softwire;
+#[cfg(feature = "spi_dma")]
+pub mod spi_dma;
+pub mod storage;
+pub mod text;
+pub mod theme;
+pub mod thermal;
+pub mod time;
+pub mod waveform;
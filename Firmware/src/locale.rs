@@ -0,0 +1,132 @@
+// 屏幕上显示的文字, 每种语言一张字符串表
+// 文字必须直接写成字符串字面量, build.rs 只会把 src 中字面量用到的字形裁剪进字体
+// 字体中缺少某种语言的字形时编译失败, 并列出是哪张表缺了哪些字, 见 fonts/README.md
+// 新增语言时在 Language 加一个分支, 再写一张 Strings 即可
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Language {
+    Chinese,
+    English,
+    Japanese,
+}
+
+impl Language {
+    pub const fn from_index(index: u8) -> Option<Self> {
+        match index {
+            0 => Some(Language::Chinese),
+            1 => Some(Language::English),
+            2 => Some(Language::Japanese),
+            _ => None,
+        }
+    }
+
+    pub const fn strings(self) -> &'static Strings {
+        match self {
+            Language::Chinese => &CHINESE,
+            Language::English => &ENGLISH,
+            Language::Japanese => &JAPANESE,
+        }
+    }
+}
+
+pub struct Strings {
+    /// 标题, 字体为 Font::Text
+    pub title: &'static str,
+    /// 默认的日期格式, 格式见 date_format::write_date
+    pub date_format: &'static str,
+    /// 星期名称, 下标与 Time::week 相同, 0为周一
+    pub weekdays: [&'static str; 7],
+    pub weekdays_short: [&'static str; 7],
+    /// 月份名称, 下标0为一月
+    pub months: [&'static str; 12],
+    pub months_short: [&'static str; 12],
+}
+
+impl Strings {
+    pub fn weekday(&self, week: u8) -> &'static str {
+        self.weekdays[week as usize % 7]
+    }
+
+    pub fn weekday_short(&self, week: u8) -> &'static str {
+        self.weekdays_short[week as usize % 7]
+    }
+
+    // month 从1开始, 与 Time::month 相同
+    pub fn month(&self, month: u8) -> &'static str {
+        self.months[(month as usize + 11) % 12]
+    }
+
+    pub fn month_short(&self, month: u8) -> &'static str {
+        self.months_short[(month as usize + 11) % 12]
+    }
+}
+
+const CJK_MONTHS: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+pub const CHINESE: Strings = Strings {
+    title: "今天是周五吗",
+    date_format: "%Y年%m月%d日",
+    weekdays: [
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+        "星期日",
+    ],
+    weekdays_short: ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+    months: CJK_MONTHS,
+    months_short: CJK_MONTHS,
+};
+
+// 横向的长条屏上文字区域只有约115px, 标题不要超过14个字符
+pub const ENGLISH: Strings = Strings {
+    title: "Is it Friday?",
+    date_format: "%a, %-d %b %Y",
+    weekdays: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    weekdays_short: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    months_short: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+};
+
+pub const JAPANESE: Strings = Strings {
+    title: "今日は金曜日?",
+    date_format: "%Y年%-m月%-d日(%a)",
+    weekdays: [
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+        "日曜日",
+    ],
+    weekdays_short: ["月", "火", "水", "木", "金", "土", "日"],
+    months: CJK_MONTHS,
+    months_short: CJK_MONTHS,
+};
@@ -5,16 +5,21 @@
 extern crate u8g2_rs;
 
 use ch58x_hal::ble::ffi::TMOS_SystemProcess;
-use ch58x_hal::gpio::{Input, Level, Output, OutputDrive, Pin, Pull};
+use ch58x_hal::gpio::{AnyPin, Input, Level, Output, OutputDrive, Pin, Pull};
 use ch58x_hal::peripherals;
 use ch58x_hal::{println, uart::UartTx};
 use embassy_executor::Spawner;
 use embassy_time::{Duration, Timer};
 use friday_rs::bluetooth::{observer_task, observer_task_init, observer_timeout_task};
-use friday_rs::display::{u8x8_byte_ch582f_hw_spi, u8x8_gpio_and_delay_ch582f, Display, DriverIC};
+use friday_rs::board::BOARD;
+use friday_rs::display::{
+    u8x8_byte_ch582f_hw_spi, u8x8_gpio_and_delay_ch582f, Display, DisplayError, Screen,
+};
+use friday_rs::refresh::RefreshPolicy;
 use friday_rs::rtc::set_default_rtc;
 use friday_rs::softwire::SoftwareI2C;
-use friday_rs::{config, power, rtc};
+use friday_rs::storage::PersistentState;
+use friday_rs::{config, power, rtc, thermal};
 
 #[panic_handler]
 fn panic(info: &core::panic::PanicInfo) -> ! {
@@ -49,6 +54,43 @@ fn print_embassy_logo() {
     println!("ChipID: 0x{:02x}", ch58x_hal::signature::get_chip_id());
 }
 
+async fn refresh(display: &mut Display, screen: &Screen, clean: bool) -> Result<(), DisplayError> {
+    display.init()?;
+    display.set_power_save(false)?;
+    if clean {
+        println!("clean refresh");
+        display.clean().await?;
+    }
+    display.show(screen).await
+}
+
+// 刷新屏幕, 失败时按错误类型决定是否重试
+async fn refresh_with_retry(
+    display: &mut Display,
+    screen: &Screen,
+    clean: bool,
+) -> Result<(), DisplayError> {
+    let mut retries = config::DISPLAY_RETRIES;
+    loop {
+        match refresh(display, screen, clean).await {
+            Ok(()) => return Ok(()),
+            // 屏幕没有响应时重试也没有意义, 直接休眠等待下一次唤醒
+            Err(DisplayError::NotResponding) => {
+                println!("display not responding");
+                return Err(DisplayError::NotResponding);
+            }
+            Err(e) if retries > 0 => {
+                println!("display error: {:?}, retry", e);
+                retries -= 1;
+            }
+            Err(e) => {
+                println!("display error: {:?}, give up", e);
+                return Err(e);
+            }
+        }
+    }
+}
+
 #[embassy_executor::main(entry = "qingke_rt::entry")]
 async fn main(spawner: Spawner) -> ! {
     let mut config = ch58x_hal::Config::default();
@@ -61,9 +103,9 @@ async fn main(spawner: Spawner) -> ! {
     }
     print_embassy_logo();
 
-    let wake_up_btn = Input::new(unsafe { peripherals::PB4::steal().degrade() }, Pull::Up);
-    let scl: u8 = p.PA13.pin() + p.PA13.port() * 32;
-    let sda_pin: u8 = p.PA12.pin() + p.PA13.port() * 32;
+    let wake_up_btn = Input::new(unsafe { AnyPin::steal(BOARD.wake_up_btn) }, Pull::Up);
+    let scl: u8 = BOARD.i2c_scl;
+    let sda_pin: u8 = BOARD.i2c_sda;
 
     static mut SOFTI2C: Option<SoftwareI2C> = None;
     static mut RTC_INSTANCE: Option<rtc::PCF8563> = None;
@@ -76,15 +118,13 @@ async fn main(spawner: Spawner) -> ! {
         set_default_rtc(RTC_INSTANCE.as_mut().unwrap());
     }
 
-    // 按需选择屏幕驱动
+    // 屏幕驱动与引脚由 board.rs 和 cargo feature 决定
     let mut display = Display::new(
-        DriverIC::SSD1607,
+        &BOARD,
         Some(u8x8_byte_ch582f_hw_spi),
         Some(u8x8_gpio_and_delay_ch582f),
     );
 
-    display.init();
-    display.set_power_save(false);
     let rtc = rtc::take();
     if cfg!(feature = "power_measure") {
         let mut now = rtc.now().unwrap();
@@ -119,20 +159,59 @@ async fn main(spawner: Spawner) -> ! {
     }
 
     let now = rtc.now().unwrap();
-
-    match boot_mode {
+    let screen = match boot_mode {
         FridayMode::TimePair => {
             println!("FridayMode::TimePair @ {:?}", now);
-            display.embassy_logo();
+            Screen::Pair
         }
         FridayMode::Normal => {
             println!("FridayMode::Normal @ {:?}", now);
-            display.is_friday(now);
+            Screen::Friday(now)
+        }
+    };
+
+    let temperature = thermal::read_celsius();
+    println!("temperature: {}C", temperature);
+    display.set_temperature(temperature);
+
+    let mut state = PersistentState::load();
+    display.set_orientation(state.settings.rotation(), state.settings.mirror());
+    display.set_language(state.settings.language());
+    display.set_date_format(state.settings.date_format());
+    let theme = state.settings.theme();
+    println!("theme: {}", theme.name);
+    display.set_theme(theme);
+    display.set_invert(state.settings.invert());
+    // 截图只在刷新时输出, 打开后画面没有变化也要刷新
+    let screenshot = cfg!(feature = "screenshot") && state.settings.screenshot();
+    #[cfg(feature = "screenshot")]
+    display.set_screenshot(screenshot);
+
+    // 画面与上一次显示的相同时不再刷新, 屏幕也不上电
+    // 到了清屏的时候即使画面相同也要刷新, 否则画面一直不变时残影永远不会被清除
+    let refresh_policy = RefreshPolicy::default();
+    let today = now.days_since_epoch();
+    let clean = refresh_policy.needs_clean(&state, today);
+    let frame_hash = display.fingerprint(|d| d.draw_screen(&screen));
+    if frame_hash == state.frame_hash && !clean && !screenshot {
+        println!("frame unchanged, skip refresh");
+        display.park();
+    } else {
+        // 刷新失败时屏幕上的内容未知, 下次必须刷新
+        state.frame_hash = match refresh_with_retry(&mut display, &screen, clean).await {
+            Ok(()) => {
+                refresh_policy.record(&mut state, today, clean);
+                frame_hash
+            }
+            Err(_) => 0,
+        };
+        state.save();
+        // waiting for epd draw done.
+        // ch58x_hal::delay_ms(5000u16);
+        if let Err(e) = display.set_power_save(true) {
+            println!("display power save error: {:?}", e);
         }
     }
-    // waiting for epd draw done.
-    // ch58x_hal::delay_ms(5000u16);
-    display.set_power_save(true);
     // ch58x_hal::delay_ms(10u16);
     match boot_mode {
         FridayMode::Normal => {
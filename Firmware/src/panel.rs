@@ -0,0 +1,238 @@
+// 各型号墨水屏的差异: BUSY极性, 上下电顺序, 是否需要DCDC, 复位时序以及休眠时的引脚状态
+// 只包含纯数据, 不依赖HAL, 可以在主机上检查休眠电流相关的配置
+// 新增屏幕时在这里加一个 PanelProfile, 再给 DriverIC 加一个分支即可, 不需要改 Display
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DriverIC {
+    // 1.54寸 200x200
+    SSD1607,
+    // 1.54寸 200x200
+    SSD1681,
+    // 2.9寸 296x128
+    IL3820,
+}
+
+impl DriverIC {
+    pub const fn profile(self) -> &'static PanelProfile {
+        match self {
+            DriverIC::SSD1607 => &SSD1607,
+            DriverIC::SSD1681 => &SSD1681,
+            DriverIC::IL3820 => &IL3820,
+        }
+    }
+}
+
+/// 输出引脚的电平
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PinLevel {
+    Low,
+    High,
+}
+
+/// 输入引脚的上下拉
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PinPull {
+    Floating,
+    Up,
+    Down,
+}
+
+/// 上电/下电过程中的一步
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PowerStep {
+    /// 打开SGM6603, 没有DCDC的屏幕忽略
+    DcdcOn,
+    /// 关闭SGM6603, 没有DCDC的屏幕忽略
+    DcdcOff,
+    /// 退出深度睡眠, u8x8_SetPowerSave(0)
+    Wake,
+    /// 进入深度睡眠, u8x8_SetPowerSave(1)
+    Sleep,
+    /// 精确延时, 不等待BUSY
+    DelayMs(u16),
+}
+
+/// 休眠时控制引脚的状态, 直接决定休眠电流
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SleepPins {
+    pub res: PinLevel,
+    pub cs: PinLevel,
+    pub busy: PinPull,
+}
+
+/// 低温下的刷新波形处理方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Compensation {
+    /// 屏幕没有温度补偿, 由MCU测温后写入LUT
+    HostLut,
+    /// 屏幕使用内部温度传感器自动选择OTP波形
+    Internal,
+    /// 还没有适合这块屏的LUT, 低温下也使用u8g2初始化时写入的波形
+    Fixed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PanelProfile {
+    pub name: &'static str,
+    /// 刷新过程中BUSY的电平
+    pub busy_level: PinLevel,
+    /// 工作时BUSY的上下拉
+    pub busy_pull: PinPull,
+    /// 屏幕供电经过SGM6603, 需要用EN脚开关
+    pub dcdc_gating: bool,
+    /// RES低电平保持时间, None使用u8g2驱动中的值
+    pub reset_pulse_ms: Option<u8>,
+    pub power_on: &'static [PowerStep],
+    pub power_off: &'static [PowerStep],
+    pub sleep_pins: SleepPins,
+    pub compensation: Compensation,
+}
+
+impl PanelProfile {
+    /// BUSY释放时的电平变化是否为下降沿
+    pub const fn busy_release_falling(&self) -> bool {
+        matches!(self.busy_level, PinLevel::High)
+    }
+}
+
+const POWER_ON: &[PowerStep] = &[PowerStep::DcdcOn, PowerStep::Wake];
+const POWER_OFF: &[PowerStep] = &[PowerStep::DcdcOff, PowerStep::Sleep];
+
+// 休眠时RES拉低, BUSY浮空
+pub const SSD1607: PanelProfile = PanelProfile {
+    name: "SSD1607",
+    busy_level: PinLevel::High,
+    busy_pull: PinPull::Up,
+    dcdc_gating: true,
+    reset_pulse_ms: None,
+    power_on: POWER_ON,
+    power_off: POWER_OFF,
+    sleep_pins: SleepPins {
+        res: PinLevel::Low,
+        cs: PinLevel::Low,
+        busy: PinPull::Floating,
+    },
+    compensation: Compensation::HostLut,
+};
+
+// 休眠时RES保持高电平, BUSY上拉
+pub const SSD1681: PanelProfile = PanelProfile {
+    name: "SSD1681",
+    busy_level: PinLevel::High,
+    busy_pull: PinPull::Up,
+    dcdc_gating: true,
+    reset_pulse_ms: None,
+    power_on: POWER_ON,
+    power_off: POWER_OFF,
+    sleep_pins: SleepPins {
+        res: PinLevel::High,
+        cs: PinLevel::Low,
+        busy: PinPull::Up,
+    },
+    compensation: Compensation::Internal,
+};
+
+// 引脚和上下电与SSD1607相同, waveform::LUT_FULL_UPDATE 是1.54寸屏的波形, 不能用于2.9寸屏
+pub const IL3820: PanelProfile = PanelProfile {
+    name: "IL3820",
+    compensation: Compensation::Fixed,
+    ..SSD1607
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [DriverIC; 3] = [DriverIC::SSD1607, DriverIC::SSD1681, DriverIC::IL3820];
+
+    #[test]
+    fn profile_matches_driver_ic() {
+        for ic in ALL {
+            assert_eq!(ic.profile().name, format!("{:?}", ic));
+        }
+    }
+
+    // 没有DCDC开关的屏幕, 上下电序列里不能出现DCDC的步骤
+    #[test]
+    fn dcdc_steps_follow_gating() {
+        for ic in ALL {
+            let profile = ic.profile();
+            let dcdc = profile
+                .power_on
+                .iter()
+                .chain(profile.power_off)
+                .any(|s| matches!(s, PowerStep::DcdcOn | PowerStep::DcdcOff));
+            assert_eq!(dcdc, profile.dcdc_gating, "{}", profile.name);
+        }
+    }
+
+    // 上电时必须先打开DCDC再唤醒, 否则唤醒命令发给了没有供电的屏幕
+    #[test]
+    fn power_on_wakes_after_dcdc() {
+        for ic in ALL {
+            let profile = ic.profile();
+            let dcdc = profile
+                .power_on
+                .iter()
+                .position(|s| *s == PowerStep::DcdcOn);
+            let wake = profile.power_on.iter().position(|s| *s == PowerStep::Wake);
+            assert!(wake.is_some(), "{}", profile.name);
+            if profile.dcdc_gating {
+                assert!(dcdc.is_some() && dcdc < wake, "{}", profile.name);
+            }
+            let sleeps = profile
+                .power_off
+                .iter()
+                .filter(|s| **s == PowerStep::Sleep)
+                .count();
+            assert_eq!(sleeps, 1, "{}", profile.name);
+            assert!(
+                !profile.power_off.contains(&PowerStep::Wake),
+                "{}",
+                profile.name
+            );
+        }
+    }
+
+    #[test]
+    fn sleep_pins() {
+        let expected = [
+            (
+                DriverIC::SSD1607,
+                PinLevel::Low,
+                PinLevel::Low,
+                PinPull::Floating,
+            ),
+            (
+                DriverIC::SSD1681,
+                PinLevel::High,
+                PinLevel::Low,
+                PinPull::Up,
+            ),
+            (
+                DriverIC::IL3820,
+                PinLevel::Low,
+                PinLevel::Low,
+                PinPull::Floating,
+            ),
+        ];
+        for (ic, res, cs, busy) in expected {
+            let pins = ic.profile().sleep_pins;
+            assert_eq!(pins, SleepPins { res, cs, busy }, "{:?}", ic);
+        }
+    }
+
+    // BUSY高电平表示忙的屏幕等下降沿, 低电平表示忙的等上升沿
+    #[test]
+    fn busy_release_matches_level() {
+        for ic in ALL {
+            let profile = ic.profile();
+            assert_eq!(
+                profile.busy_release_falling(),
+                profile.busy_level == PinLevel::High,
+                "{}",
+                profile.name
+            );
+        }
+    }
+}
@@ -3,7 +3,10 @@ use ch58x_hal::{isp::flash_rom_reset, with_safe_access};
 use qingke::riscv;
 use qingke_rt::highcode;
 
-use crate::{gpio::{GPIO_PIN_22, GPIO_PIN_23, GPIO_PIN_4}, regs};
+use crate::{
+    gpio::{GPIO_PIN_22, GPIO_PIN_23, GPIO_PIN_4},
+    regs,
+};
 #[derive(Clone, Copy)]
 pub enum SysClk {
     ClkSourceLsi = 0x00,
@@ -26,7 +29,23 @@ pub enum SysClk {
     ClkSourcePll15mhz = (0x40 | 0),
 }
 
-fn set_sys_clock(sysclk: SysClk) {
+// 根据 R8_CLK_SYS_CFG 计算当前实际的系统时钟频率
+pub fn sys_clock_hz() -> u32 {
+    let sys = unsafe { ch58x::SYS::steal() };
+    let cfg = sys.clk_sys_cfg().read().bits() as u32;
+    let div = match cfg & 0x1f {
+        0 => 32,
+        div => div,
+    };
+    match (cfg >> 6) & 0b11 {
+        0b00 => 32_000_000 / div,
+        0b01 => 480_000_000 / div,
+        0b10 => 32_000_000,
+        _ => 32_768,
+    }
+}
+
+pub fn set_sys_clock(sysclk: SysClk) {
     /*
        R8_SAFE_ACCESS_SIG = SAFE_ACCESS_SIG1;
        R8_SAFE_ACCESS_SIG = SAFE_ACCESS_SIG2;
@@ -103,6 +122,89 @@ fn set_sys_clock(sysclk: SysClk) {
     })
 }
 
+// RTC 32KHz计数, 与系统时钟无关
+pub fn rtc_ticks() -> u32 {
+    let sys = unsafe { ch58x::SYS::steal() };
+    sys.rtc_cnt_32k().read().bits() as u32
+}
+
+// RTC计数满一天(86400 * 32768)后回到0
+const RTC_PERIOD: u32 = 0xA8C0_0000;
+
+const RTC_IRQN: u8 = 28;
+
+// PFIC 中断使能/禁止/挂起清除寄存器, 中断号0~31
+const PFIC_IENR1: *mut u32 = 0xE000_E100 as *mut u32;
+const PFIC_IRER1: *mut u32 = 0xE000_E180 as *mut u32;
+const PFIC_IPRR1: *mut u32 = 0xE000_E280 as *mut u32;
+
+pub fn enable_irq(irqn: u8) {
+    unsafe { core::ptr::write_volatile(PFIC_IENR1, 1 << irqn) };
+}
+
+pub fn disable_irq(irqn: u8) {
+    unsafe { core::ptr::write_volatile(PFIC_IRER1, 1 << irqn) };
+}
+
+// 清除未使能中断在PFIC中的挂起位
+pub fn clear_pending(irqn: u8) {
+    unsafe { core::ptr::write_volatile(PFIC_IPRR1, 1 << irqn) };
+}
+
+// 进入idle直到 done() 返回true
+// 唤醒源为挂起的外设中断(SEVONPEND), 调用者需要保证对应外设的中断标志会被置起
+pub fn idle(mut done: impl FnMut() -> bool) {
+    let pfic = unsafe { ch58x::PFIC::steal() };
+    // SEVONPEND(bit4): 未使能的中断挂起也能唤醒
+    // WFITOWFE(bit3): wfi按wfe执行, 唤醒后不进入中断服务函数
+    // SLEEPDEEP(bit2)清零: 只是idle, 外设保持运行
+    pfic.sctlr()
+        .modify(|r, w| unsafe { w.bits((r.bits() | (1 << 4) | (1 << 3)) & !(1 << 2)) });
+    while !done() {
+        unsafe {
+            riscv::asm::wfi();
+        }
+    }
+    pfic.sctlr()
+        .modify(|r, w| unsafe { w.bits(r.bits() & !((1 << 4) | (1 << 3))) });
+}
+
+// 进入idle, 直到 done() 返回true或者超时, 超时返回false
+// 超时由RTC触发保证能够醒来
+// 不修改系统时钟: embassy的时间驱动按HCLK计数SysTick, 降频会让所有定时器变慢
+// 刷新时的长时间等待走 gpio::wait_for_edge, 这里只用于u8g2回调中无法await的短等待
+pub fn idle_until(timeout_ms: u32, mut done: impl FnMut() -> bool) -> bool {
+    let sys = unsafe { ch58x::SYS::steal() };
+    if done() {
+        return true;
+    }
+    // 超时不能超过一个RTC周期, 否则比较结果会回绕
+    let ticks = (timeout_ms as u64 * 32768 / 1000).min(RTC_PERIOD as u64 - 1);
+    let deadline = ((rtc_ticks() as u64 + ticks) % RTC_PERIOD as u64) as u32;
+    sys.rtc_flag_ctrl()
+        .write(|w| unsafe { w.bits(regs::RB_RTC_TRIG_CLR) });
+    with_safe_access(|| unsafe {
+        sys.rtc_trig().write(|w| w.bits(deadline));
+        sys.rtc_mode_ctrl()
+            .modify(|r, w| w.bits(r.bits() | regs::RB_RTC_TRIG_EN));
+    });
+
+    let mut released = false;
+    idle(|| {
+        released = done();
+        released || sys.rtc_flag_ctrl().read().bits() & regs::RB_RTC_TRIG_FLAG != 0
+    });
+
+    with_safe_access(|| unsafe {
+        sys.rtc_mode_ctrl()
+            .modify(|r, w| w.bits(r.bits() & !regs::RB_RTC_TRIG_EN));
+    });
+    sys.rtc_flag_ctrl()
+        .write(|w| unsafe { w.bits(regs::RB_RTC_TRIG_CLR) });
+    clear_pending(RTC_IRQN);
+    released
+}
+
 #[highcode]
 pub fn low_power_shutdown(rm: u8) {
     let sys = unsafe { ch58x::SYS::steal() };
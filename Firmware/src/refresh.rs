@@ -0,0 +1,40 @@
+// 定期全刷清屏策略, 抑制部分刷新/快速刷新积累下来的残影
+// 清屏通过 Display::clean 完成, 与屏幕驱动使用哪种刷新方式无关
+use crate::config;
+use crate::storage::PersistentState;
+
+pub struct RefreshPolicy {
+    // 每刷新多少次清屏一次, 0表示不按次数清屏
+    pub every_updates: u16,
+    // 距离上次清屏多少天后清屏一次, 0表示不按天数清屏
+    pub every_days: u16,
+}
+
+impl Default for RefreshPolicy {
+    fn default() -> Self {
+        Self {
+            every_updates: config::CLEAN_EVERY_UPDATES,
+            every_days: config::CLEAN_EVERY_DAYS,
+        }
+    }
+}
+
+impl RefreshPolicy {
+    // today: 自1970-01-01起的天数
+    pub fn needs_clean(&self, state: &PersistentState, today: u16) -> bool {
+        let by_count = self.every_updates != 0 && state.updates_since_clean >= self.every_updates;
+        let by_days =
+            self.every_days != 0 && today.wrapping_sub(state.last_clean_day) >= self.every_days;
+        by_count || by_days
+    }
+
+    // 每次成功刷新之后调用
+    pub fn record(&self, state: &mut PersistentState, today: u16, cleaned: bool) {
+        if cleaned {
+            state.updates_since_clean = 0;
+            state.last_clean_day = today;
+        } else {
+            state.updates_since_clean = state.updates_since_clean.saturating_add(1);
+        }
+    }
+}
@@ -15,3 +15,6 @@ pub const RB_PWR_MUST_0010: u16 = 0x1000u16; // RWA, must write 0010
 pub const RB_SLP_GPIO_WAKE: u8 = 0x10; // RWA, enable GPIO waking
 pub const RB_WAKE_EV_MODE: u8 = 0x40; // RWA, event wakeup mode: 0=event keep valid for long time, 1=short pulse event
 
+pub const RB_RTC_TRIG_EN: u8 = 0x20; // RWA, RTC trigger mode enable
+pub const RB_RTC_TRIG_CLR: u8 = 0x20; // RW, set 1 to clear RTC trigger action flag, auto clear
+pub const RB_RTC_TRIG_FLAG: u8 = 0x80; // RO, RTC trigger action flag
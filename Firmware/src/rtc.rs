@@ -2,6 +2,7 @@ use core::cell::RefCell;
 
 use crate::softwire::SoftwareI2C;
 use crate::softwire::WriteReg;
+pub use crate::time::Time;
 use ch58x_hal::{
     i2c,
     prelude::{_embedded_hal_blocking_i2c_Write, _embedded_hal_blocking_i2c_WriteRead},
@@ -15,17 +16,6 @@ pub struct PCF8563 {
     addr: u8,
 }
 
-#[derive(Debug)]
-pub struct Time {
-    pub year: u8,
-    pub month: u8,
-    pub day: u8,
-    pub hour: u8,
-    pub minute: u8,
-    pub second: u8,
-    pub week: u8,
-}
-
 #[allow(unused)]
 mod regs {
     pub const PCF8563_CLKOUTCONTROL: u8 = 0x0d; // Bit 7 PWR_MODE, bits 6:1 XG_OFFS_TC, bit 0 OTP_BNK_VLD
@@ -0,0 +1,59 @@
+// 把u8g2的缓冲区通过UART1以PBM或XBM文本输出, 用 tools/screenshot.py 转换成PNG
+// 输出夹在 BEGIN/END 两行之间, 可以直接从串口日志里截取
+// 三色和灰度模式只输出u8g2自己的缓冲区(黑色/灰度高位)
+use core::ffi::{c_char, CStr};
+
+use crate::config;
+use ch58x_hal::{print, println};
+use u8g2_rs::*;
+
+const BEGIN: &str = "-----BEGIN SCREENSHOT-----";
+const END: &str = "-----END SCREENSHOT-----";
+
+unsafe extern "C" fn out(s: *const c_char) {
+    if let Ok(s) = CStr::from_ptr(s).to_str() {
+        print!("{}", s);
+    }
+}
+
+/// 输出文件头, tile_height 为整屏的tile行数
+/// orientation 为当前的(旋转角度, 镜像), 缓冲区是屏幕的原始方向, 转换时据此还原
+pub fn begin(tile_width: u8, tile_height: u8, orientation: (u16, bool)) {
+    println!("{}", BEGIN);
+    println!("rotation {} mirror {}", orientation.0, orientation.1 as u8);
+    unsafe {
+        if config::SCREENSHOT_XBM {
+            u8x8_capture_write_xbm_pre(tile_width, tile_height, Some(out));
+        } else {
+            u8x8_capture_write_pbm_pre(tile_width, tile_height, Some(out));
+        }
+    }
+}
+
+/// 输出一段缓冲区, 分页模式下每页调用一次, 按从上到下的顺序
+pub fn write(buffer: &[u8], tile_width: u8, tile_height: u8) {
+    let buffer = buffer.as_ptr() as *mut u8;
+    unsafe {
+        if config::SCREENSHOT_XBM {
+            u8x8_capture_write_xbm_buffer(
+                buffer,
+                tile_width,
+                tile_height,
+                Some(u8x8_capture_get_pixel_1),
+                Some(out),
+            );
+        } else {
+            u8x8_capture_write_pbm_buffer(
+                buffer,
+                tile_width,
+                tile_height,
+                Some(u8x8_capture_get_pixel_1),
+                Some(out),
+            );
+        }
+    }
+}
+
+pub fn end() {
+    println!("{}", END);
+}
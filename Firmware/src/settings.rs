@@ -0,0 +1,124 @@
+// 可以从App修改并掉电保存的设置, 存放在 PersistentState 中
+// App通过广播名称发送: 'S' + 设置项 + 值的16进制字符串 + 'R', 例如 "Sr1R" 表示旋转90度
+// 自定义日期格式直接发送格式字符串: 'P' + 格式 + 'R', 例如 "P%d/%m/%yR", "PR" 清除自定义格式
+use crate::board::BOARD;
+use crate::config;
+use crate::date_format::{Pattern, DATE_FORMATS};
+use crate::display::Rotation;
+use crate::locale::Language;
+use crate::theme::{Theme, THEMES};
+
+// 旋转方向, 值为 0/1/2/3, 对应 0/90/180/270 度
+pub const KEY_ROTATION: u8 = b'r';
+// 左右镜像, 值为 0/1
+pub const KEY_MIRROR: u8 = b'm';
+// 显示语言, 值为 0/1/2, 对应中文/英文/日文
+pub const KEY_LANGUAGE: u8 = b'l';
+// 日期格式, 值为 date_format::DATE_FORMATS 的下标
+pub const KEY_DATE_FORMAT: u8 = b'd';
+// 主题, 值为 theme::THEMES 的下标, 0为默认主题
+pub const KEY_THEME: u8 = b't';
+// 反色显示, 值为 0/1
+pub const KEY_INVERT: u8 = b'i';
+// 每次刷新时从UART1输出截图, 值为 0/1, 编译时关闭 screenshot feature 后无效
+pub const KEY_SCREENSHOT: u8 = b'c';
+
+// 没有设置过, 使用编译时的默认值
+const UNSET: u8 = 0xFF;
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Settings {
+    rotation: u8,
+    mirror: u8,
+    language: u8,
+    date_format: u8,
+    theme: u8,
+    invert: u8,
+    screenshot: u8,
+    // 自定义日期格式, 优先于 date_format
+    date_pattern: Pattern,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            rotation: UNSET,
+            mirror: 0,
+            language: UNSET,
+            date_format: UNSET,
+            theme: 0,
+            invert: 0,
+            screenshot: 0,
+            date_pattern: Pattern::EMPTY,
+        }
+    }
+}
+
+impl Settings {
+    // 没有设置过时使用 rotation_* feature 选择的方向
+    pub fn rotation(&self) -> Rotation {
+        Rotation::from_index(self.rotation).unwrap_or(BOARD.rotation)
+    }
+
+    pub fn mirror(&self) -> bool {
+        self.mirror != 0
+    }
+
+    // 没有设置过时使用 config::DEFAULT_LANGUAGE
+    pub fn language(&self) -> Language {
+        Language::from_index(self.language).unwrap_or(config::DEFAULT_LANGUAGE)
+    }
+
+    // 优先使用自定义格式, 其次是选择的预设格式, 都没有设置过时使用当前语言的默认格式
+    pub fn date_format(&self) -> &str {
+        if !self.date_pattern.is_empty() {
+            return self.date_pattern.as_str();
+        }
+        DATE_FORMATS
+            .get(self.date_format as usize)
+            .copied()
+            .unwrap_or(self.language().strings().date_format)
+    }
+
+    pub fn theme(&self) -> &'static Theme {
+        Theme::from_index(self.theme)
+    }
+
+    pub fn invert(&self) -> bool {
+        self.invert != 0
+    }
+
+    pub fn screenshot(&self) -> bool {
+        self.screenshot != 0
+    }
+
+    // 返回设置是否有变化, 未知的设置项或者超出范围的值会被忽略
+    pub fn set(&mut self, key: u8, value: u32) -> bool {
+        let old = *self;
+        match (key, value) {
+            (KEY_ROTATION, 0..=3) => self.rotation = value as u8,
+            (KEY_MIRROR, 0..=1) => self.mirror = value as u8,
+            (KEY_LANGUAGE, 0..=2) => self.language = value as u8,
+            (KEY_DATE_FORMAT, _) if (value as usize) < DATE_FORMATS.len() => {
+                self.date_format = value as u8;
+                self.date_pattern = Pattern::EMPTY;
+            }
+            (KEY_THEME, _) if (value as usize) < THEMES.len() => self.theme = value as u8,
+            (KEY_INVERT, 0..=1) => self.invert = value as u8,
+            (KEY_SCREENSHOT, 0..=1) => self.screenshot = value as u8,
+            _ => return false,
+        }
+        *self != old
+    }
+
+    // 保存之前用 write_date 检查格式, 不能使用的格式会被忽略, 返回设置是否有变化
+    pub fn set_date_pattern(&mut self, pattern: &[u8]) -> bool {
+        let old = *self;
+        match core::str::from_utf8(pattern).ok().and_then(Pattern::new) {
+            Some(pattern) => self.date_pattern = pattern,
+            None => return false,
+        }
+        *self != old
+    }
+}
@@ -0,0 +1,59 @@
+// SPI0 DMA发送, 用于把帧缓冲区批量推给墨水屏
+// DMA只能访问RAM, 地址寄存器只有低16位有效
+use crate::power;
+
+const R8_SPI0_CTRL_MOD: *mut u8 = 0x4000_4000 as *mut u8;
+const R8_SPI0_CTRL_CFG: *mut u8 = 0x4000_4001 as *mut u8;
+const R8_SPI0_INTER_EN: *mut u8 = 0x4000_4002 as *mut u8;
+const R8_SPI0_INT_FLAG: *mut u8 = 0x4000_4006 as *mut u8;
+const R16_SPI0_TOTAL_CNT: *mut u16 = 0x4000_400C as *mut u16;
+const R16_SPI0_DMA_BEG: *mut u16 = 0x4000_4018 as *mut u16;
+const R16_SPI0_DMA_END: *mut u16 = 0x4000_401C as *mut u16;
+
+const RB_SPI_FIFO_DIR: u8 = 0x10; // RW, SPI FIFO direction: 0=out(write @master mode), 1=in(read)
+const RB_SPI_DMA_ENABLE: u8 = 0x01; // RW, enable DMA function
+const RB_SPI_IE_CNT_END: u8 = 0x02; // RW, enable interrupt for SPI total byte count end
+const RB_SPI_IF_CNT_END: u8 = 0x02; // RW1, interrupt flag for SPI total byte count end
+const RB_SPI_IF_DMA_END: u8 = 0x08; // RW1, interrupt flag for SPI DMA completion
+const RB_SPI_FREE: u8 = 0x40; // RO, current SPI free status
+
+const SPI0_IRQN: u8 = 19;
+
+const RAM_START: usize = 0x2000_0000;
+const RAM_END: usize = 0x2000_8000;
+
+// 数据不在RAM中(比如flash里的常量)时无法使用DMA
+pub fn can_dma(data: &[u8]) -> bool {
+    let start = data.as_ptr() as usize;
+    start >= RAM_START && start + data.len() <= RAM_END && data.len() <= u16::MAX as usize
+}
+
+// 阻塞发送, 等待期间CPU进入idle, 由SPI0计数结束中断的挂起位唤醒
+pub fn write(data: &[u8]) {
+    if data.is_empty() {
+        return;
+    }
+    let start = data.as_ptr() as usize;
+    unsafe {
+        let ctrl_mod = core::ptr::read_volatile(R8_SPI0_CTRL_MOD);
+        core::ptr::write_volatile(R8_SPI0_CTRL_MOD, ctrl_mod & !RB_SPI_FIFO_DIR);
+        core::ptr::write_volatile(R16_SPI0_DMA_BEG, start as u16);
+        core::ptr::write_volatile(R16_SPI0_DMA_END, (start + data.len()) as u16);
+        core::ptr::write_volatile(R8_SPI0_INT_FLAG, RB_SPI_IF_CNT_END | RB_SPI_IF_DMA_END);
+        power::clear_pending(SPI0_IRQN);
+        core::ptr::write_volatile(R8_SPI0_INTER_EN, RB_SPI_IE_CNT_END);
+        core::ptr::write_volatile(R16_SPI0_TOTAL_CNT, data.len() as u16);
+        let ctrl_cfg = core::ptr::read_volatile(R8_SPI0_CTRL_CFG);
+        core::ptr::write_volatile(R8_SPI0_CTRL_CFG, ctrl_cfg | RB_SPI_DMA_ENABLE);
+
+        power::idle(|| core::ptr::read_volatile(R8_SPI0_INT_FLAG) & RB_SPI_IF_CNT_END != 0);
+        // 计数结束后最后一个字节可能还在移位
+        while core::ptr::read_volatile(R8_SPI0_INT_FLAG) & RB_SPI_FREE == 0 {}
+
+        let ctrl_cfg = core::ptr::read_volatile(R8_SPI0_CTRL_CFG);
+        core::ptr::write_volatile(R8_SPI0_CTRL_CFG, ctrl_cfg & !RB_SPI_DMA_ENABLE);
+        core::ptr::write_volatile(R8_SPI0_INTER_EN, 0);
+        core::ptr::write_volatile(R8_SPI0_INT_FLAG, RB_SPI_IF_CNT_END | RB_SPI_IF_DMA_END);
+        power::clear_pending(SPI0_IRQN);
+    }
+}
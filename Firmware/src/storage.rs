@@ -0,0 +1,95 @@
+// 掉电保存的状态, 存放在DataFlash(EEPROM)中
+// shutdown模式唤醒后会复位, RAM内容无法保留, 所以使用flash
+// DataFlash末尾留给BLE的SNV使用, 这里只占用开头的一个块
+use core::ffi::c_void;
+
+use crate::settings::Settings;
+
+extern "C" {
+    fn FLASH_EEPROM_CMD(cmd: u8, start_addr: u32, buffer: *mut c_void, len: u32) -> u32;
+}
+
+const CMD_EEPROM_ERASE: u8 = 0x09;
+const CMD_EEPROM_WRITE: u8 = 0x0A;
+const CMD_EEPROM_READ: u8 = 0x0B;
+
+const EEPROM_BLOCK_SIZE: u32 = 256;
+const STATE_ADDR: u32 = 0;
+// 结构体布局变化时需要修改, 旧数据会被丢弃并恢复默认值
+const STATE_MAGIC: u32 = 0x4652_4909;
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PersistentState {
+    magic: u32,
+    // 上一次显示在屏幕上的画面的哈希, 0表示未知
+    pub frame_hash: u32,
+    // 上次清屏之后的刷新次数
+    pub updates_since_clean: u16,
+    // 上次清屏的日期, 自1970-01-01起的天数
+    pub last_clean_day: u16,
+    pub settings: Settings,
+}
+
+impl Default for PersistentState {
+    fn default() -> Self {
+        Self {
+            magic: STATE_MAGIC,
+            frame_hash: 0,
+            updates_since_clean: 0,
+            last_clean_day: 0,
+            settings: Settings::default(),
+        }
+    }
+}
+
+const _: () = assert!(core::mem::size_of::<PersistentState>() as u32 <= EEPROM_BLOCK_SIZE);
+
+impl PersistentState {
+    fn read() -> Option<Self> {
+        let mut state = core::mem::MaybeUninit::<Self>::uninit();
+        let ret = unsafe {
+            FLASH_EEPROM_CMD(
+                CMD_EEPROM_READ,
+                STATE_ADDR,
+                state.as_mut_ptr() as *mut c_void,
+                core::mem::size_of::<Self>() as u32,
+            )
+        };
+        if ret != 0 {
+            return None;
+        }
+        let state = unsafe { state.assume_init() };
+        if state.magic == STATE_MAGIC {
+            Some(state)
+        } else {
+            None
+        }
+    }
+
+    pub fn load() -> Self {
+        Self::read().unwrap_or_default()
+    }
+
+    // 内容没有变化时不写入, 减少flash擦写
+    pub fn save(&self) {
+        if Self::read().as_ref() == Some(self) {
+            return;
+        }
+        let mut state = *self;
+        unsafe {
+            FLASH_EEPROM_CMD(
+                CMD_EEPROM_ERASE,
+                STATE_ADDR,
+                core::ptr::null_mut(),
+                EEPROM_BLOCK_SIZE,
+            );
+            FLASH_EEPROM_CMD(
+                CMD_EEPROM_WRITE,
+                STATE_ADDR,
+                &mut state as *mut Self as *mut c_void,
+                core::mem::size_of::<Self>() as u32,
+            );
+        }
+    }
+}
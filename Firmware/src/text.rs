@@ -0,0 +1,276 @@
+// 文字相关的辅助工具: 带容量检查的格式化缓冲区, 转换成C字符串, 对齐和自动换行
+// 只做字符串处理, 文字宽度由调用者(Display)测量
+use core::ffi::c_char;
+use core::fmt;
+
+use crate::layout::Rect;
+
+/// 固定容量的字符串缓冲区, 末尾总是保留一个字节给NUL
+/// 写入超出容量时返回 `fmt::Error`, 已写入的内容保持不变
+pub struct TextBuf<const N: usize> {
+    buffer: [u8; N],
+    pos: usize,
+}
+
+impl<const N: usize> TextBuf<N> {
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            pos: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // 只会写入完整的 &str, 一定是合法的UTF-8
+        unsafe { core::str::from_utf8_unchecked(&self.buffer[..self.pos]) }
+    }
+
+    pub fn clear(&mut self) {
+        self.pos = 0;
+    }
+
+    // 以NUL结尾的C字符串, 只在传给u8g2时使用
+    pub fn as_c_ptr(&mut self) -> *const c_char {
+        self.buffer[self.pos] = 0;
+        self.buffer.as_ptr() as *const c_char
+    }
+}
+
+impl<const N: usize> Default for TextBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Write for TextBuf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+        if self.pos + bytes.len() >= N {
+            return Err(fmt::Error);
+        }
+        self.buffer[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+        Ok(())
+    }
+}
+
+/// 一次传给u8g2的最大字节数(不含NUL), 更长的文字会按字符边界分段
+pub const CHUNK_LEN: usize = 127;
+
+/// 把文字分段转换成C字符串, 每段不超过 CHUNK_LEN 字节且不会截断字符
+pub fn for_each_c_str(text: &str, mut f: impl FnMut(*const c_char)) {
+    let mut buf = TextBuf::<{ CHUNK_LEN + 1 }>::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let mut end = rest.len().min(CHUNK_LEN);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        buf.clear();
+        let _ = fmt::Write::write_str(&mut buf, &rest[..end]);
+        f(buf.as_c_ptr());
+        rest = &rest[end..];
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl HAlign {
+    /// 宽度为 text_width 的文字在区域内的起点x
+    pub fn x(self, area: Rect, text_width: u16) -> i16 {
+        let space = area.w as i16 - text_width as i16;
+        match self {
+            HAlign::Left => area.x,
+            HAlign::Center => area.x + space / 2,
+            HAlign::Right => area.x + space,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VAlign {
+    Top,
+    Middle,
+    Bottom,
+}
+
+impl VAlign {
+    /// 字体的上伸部为 ascent, 下伸部为 descent(负数)时, 文字在区域内的基线y
+    pub fn baseline(self, area: Rect, ascent: i8, descent: i8) -> i16 {
+        let height = ascent as i16 - descent as i16;
+        match self {
+            VAlign::Top => area.y + ascent as i16,
+            VAlign::Middle => area.y + (area.h as i16 - height) / 2 + ascent as i16,
+            VAlign::Bottom => area.y + area.h as i16 + descent as i16,
+        }
+    }
+}
+
+// 中日韩文字之间可以直接换行, 西文只在空格处换行
+fn breaks_anywhere(c: char) -> bool {
+    c >= '\u{2E80}'
+}
+
+/// 按宽度把文字拆成多行, 每次调用 next_line 取出一行
+/// 手动换行符 '\n' 总是换行, 一个单词比整行还宽时单独占一行
+pub struct WordWrap<'a> {
+    rest: &'a str,
+    max_width: u16,
+}
+
+impl<'a> WordWrap<'a> {
+    pub fn new(text: &'a str, max_width: u16) -> Self {
+        Self {
+            rest: text,
+            max_width,
+        }
+    }
+
+    pub fn next_line(&mut self, mut measure: impl FnMut(&str) -> u16) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let text = self.rest;
+        // 当前行可以结束的位置(不含行尾空格), 下一行开头的空格会被跳过
+        let mut fit: Option<usize> = None;
+        let mut chars = text.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let next = chars.peek().map(|(j, _)| *j).unwrap_or(text.len());
+            if c == '\n' {
+                self.rest = &text[next..];
+                return Some(&text[..i]);
+            }
+            let next_c = chars.peek().map(|(_, c)| *c);
+            let can_break = match next_c {
+                Some(n) => n == ' ' || n == '\n' || breaks_anywhere(c) || breaks_anywhere(n),
+                None => true,
+            };
+            if c == ' ' || !can_break {
+                continue;
+            }
+            if measure(&text[..next]) > self.max_width {
+                break;
+            }
+            fit = Some(next);
+        }
+        // 第一个单词就放不下时, 只能整个放在这一行
+        let end = fit.unwrap_or_else(|| {
+            text.char_indices()
+                .skip(1)
+                .find(|(_, c)| *c == ' ' || *c == '\n' || breaks_anywhere(*c))
+                .map(|(i, _)| i)
+                .unwrap_or(text.len())
+        });
+        let rest = text[end..].trim_start_matches(' ');
+        // 放不下的单词后面紧跟换行符时, 这个换行已经由本行完成, 不再多出一个空行
+        self.rest = match fit {
+            None => rest.strip_prefix('\n').unwrap_or(rest),
+            Some(_) => rest,
+        };
+        Some(&text[..end])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn text_buf_write() {
+        let mut buf = TextBuf::<16>::new();
+        write!(buf, "{}-{:02}", 2024, 3).unwrap();
+        assert_eq!(buf.as_str(), "2024-03");
+        buf.clear();
+        assert_eq!(buf.as_str(), "");
+        buf.write_str("周五").unwrap();
+        assert_eq!(buf.as_str(), "周五");
+    }
+
+    // 末尾保留一个字节给NUL, 写满时返回错误且不写入半截内容
+    #[test]
+    fn text_buf_capacity() {
+        let mut buf = TextBuf::<8>::new();
+        buf.write_str("1234567").unwrap();
+        assert!(buf.write_str("8").is_err());
+        assert_eq!(buf.as_str(), "1234567");
+
+        let mut buf = TextBuf::<8>::new();
+        buf.write_str("abcde").unwrap();
+        assert!(buf.write_str("五").is_err());
+        assert_eq!(buf.as_str(), "abcde");
+    }
+
+    #[test]
+    fn text_buf_c_str() {
+        let mut buf = TextBuf::<8>::new();
+        buf.write_str("abc").unwrap();
+        let s = unsafe { core::ffi::CStr::from_ptr(buf.as_c_ptr()) };
+        assert_eq!(s.to_bytes(), b"abc");
+    }
+
+    #[test]
+    fn c_str_chunks_keep_chars() {
+        let text = "周".repeat(100);
+        let mut chunks = Vec::new();
+        for_each_c_str(&text, |p| {
+            let s = unsafe { core::ffi::CStr::from_ptr(p) };
+            chunks.push(s.to_str().unwrap().to_owned());
+        });
+        assert!(chunks.iter().all(|c| c.len() <= CHUNK_LEN));
+        assert_eq!(chunks.concat(), text);
+    }
+
+    // 每个字符宽度为1
+    fn wrap(text: &str, max_width: u16) -> Vec<&str> {
+        let mut wrap = WordWrap::new(text, max_width);
+        let mut lines = Vec::new();
+        while let Some(line) = wrap.next_line(|s| s.chars().count() as u16) {
+            lines.push(line);
+        }
+        lines
+    }
+
+    #[test]
+    fn wraps_at_spaces() {
+        assert_eq!(wrap("Is it Friday?", 8), ["Is it", "Friday?"]);
+        assert_eq!(wrap("Is it Friday?", 13), ["Is it Friday?"]);
+        assert_eq!(wrap("a b c d", 3), ["a b", "c d"]);
+    }
+
+    #[test]
+    fn cjk_breaks_anywhere() {
+        assert_eq!(wrap("今天是周五吗", 4), ["今天是周", "五吗"]);
+        assert_eq!(wrap("今日は金曜日?", 4), ["今日は金", "曜日?"]);
+    }
+
+    #[test]
+    fn newline_always_breaks() {
+        assert_eq!(wrap("ab\ncd", 10), ["ab", "cd"]);
+        assert_eq!(wrap("ab\n\ncd", 10), ["ab", "", "cd"]);
+        assert_eq!(wrap("ab cd\nef", 3), ["ab", "cd", "ef"]);
+    }
+
+    #[test]
+    fn overlong_word_gets_own_line() {
+        assert_eq!(wrap("Wednesday is", 4), ["Wednesday", "is"]);
+        assert_eq!(wrap("a Wednesday", 4), ["a", "Wednesday"]);
+    }
+
+    #[test]
+    fn overlong_word_before_newline() {
+        assert_eq!(wrap("Wednesday\nok", 4), ["Wednesday", "ok"]);
+        assert_eq!(wrap("Wednesday \nok", 4), ["Wednesday", "ok"]);
+        assert_eq!(wrap("Wednesday\n\nok", 4), ["Wednesday", "", "ok"]);
+    }
+
+    #[test]
+    fn empty_text() {
+        assert!(wrap("", 10).is_empty());
+    }
+}
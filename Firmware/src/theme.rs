@@ -0,0 +1,44 @@
+// 主题: 每天的结论图片, 字体和文字位置的组合
+// 0号主题为内置的默认主题, 其余由 build.rs 从 themes/ 下的目录生成, 添加主题不需要修改代码
+use crate::assets::{Image, NOPE_ANSWER, YES_ANSWER};
+use crate::display::Font;
+use crate::layout::{LayoutParams, DEFAULT_PARAMS};
+
+pub struct Theme {
+    pub name: &'static str,
+    /// 每天显示的结论图片, 下标与 Time::week 相同, 0为周一
+    /// 图片尺寸可以不同, 绘制时会按图片大小重新排版
+    pub verdicts: [&'static Image; 7],
+    pub title_font: Font,
+    pub date_font: Font,
+    pub layout: LayoutParams,
+}
+
+impl Theme {
+    /// 按设置值选择主题, 超出范围时使用默认主题
+    pub fn from_index(index: u8) -> &'static Theme {
+        THEMES.get(index as usize).copied().unwrap_or(&DEFAULT)
+    }
+
+    pub fn verdict(&self, week: u8) -> &'static Image {
+        self.verdicts[week as usize % 7]
+    }
+}
+
+pub static DEFAULT: Theme = Theme {
+    name: "default",
+    verdicts: [
+        &NOPE_ANSWER,
+        &NOPE_ANSWER,
+        &NOPE_ANSWER,
+        &NOPE_ANSWER,
+        &YES_ANSWER,
+        &NOPE_ANSWER,
+        &NOPE_ANSWER,
+    ],
+    title_font: Font::Text,
+    date_font: Font::Text,
+    layout: DEFAULT_PARAMS,
+};
+
+include!(concat!(env!("OUT_DIR"), "/themes.rs"));
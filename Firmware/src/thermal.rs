@@ -0,0 +1,78 @@
+// 芯片内部温度传感器, 用于选择墨水屏的刷新波形
+// 换算与WCH SDK中 CH58x_adc.c 的 adc_to_temperature_celsius 相同, 使用信息区中的出厂校准值
+const R8_ADC_CHANNEL: *mut u8 = 0x4000_1058 as *mut u8;
+const R8_ADC_CFG: *mut u8 = 0x4000_1059 as *mut u8;
+const R8_ADC_CONVERT: *mut u8 = 0x4000_105A as *mut u8;
+const R8_TEM_SENSOR: *mut u8 = 0x4000_105B as *mut u8;
+const R16_ADC_DATA: *const u16 = 0x4000_105C as *const u16;
+// 出厂校准: 低16位为25度时的ADC值, 高16位为校准时的温度, 为0时按25度
+const ROM_CFG_TMP_25C: *const u32 = 0x0007_F014 as *const u32;
+
+const CH_INTE_VTEMP: u8 = 15; // 内部温度传感器通道
+const RB_ADC_POWER_ON: u8 = 0x01; // RW, ADC power control: 0=power down, 1=power on
+const RB_ADC_DIFF_EN: u8 = 0x04; // RW, ADC input channel mode: 0=single-end, 1=differnetial
+const RB_ADC_PGA_GAIN_6DB: u8 = 0x30; // RW, +6dB增益, 与SDK的 ADC_InterTSSampInit 相同
+const RB_ADC_START: u8 = 0x01; // RW, ADC convert start control: 0=stop ADC convert, 1=start an ADC convert, auto clear
+const RB_TEM_SEN_PWR_ON: u8 = 0x80; // RW, temperature sensor power control: 0=power down, 1=power on
+const RB_ADC_DATA: u16 = 0x0FFF; // RO, ADC conversion data
+
+const SAMPLES: u32 = 8;
+
+// ADC值每变化2.7对应1度
+fn adc_to_celsius(adc: u16, calibration: u32) -> i32 {
+    let base = match calibration >> 16 {
+        0 => 25,
+        celsius => celsius as i32,
+    };
+    base + (adc as i32 - (calibration & 0xFFFF) as i32) * 10 / 27
+}
+
+fn convert() -> u16 {
+    unsafe {
+        core::ptr::write_volatile(R8_ADC_CONVERT, RB_ADC_START);
+        while core::ptr::read_volatile(R8_ADC_CONVERT) & RB_ADC_START != 0 {}
+        core::ptr::read_volatile(R16_ADC_DATA) & RB_ADC_DATA
+    }
+}
+
+// 读取芯片温度, 单位摄氏度. 读取完毕后关闭ADC和温度传感器
+pub fn read_celsius() -> i8 {
+    unsafe {
+        let tem_sensor = core::ptr::read_volatile(R8_TEM_SENSOR);
+        core::ptr::write_volatile(R8_TEM_SENSOR, tem_sensor | RB_TEM_SEN_PWR_ON);
+        core::ptr::write_volatile(R8_ADC_CHANNEL, CH_INTE_VTEMP);
+        core::ptr::write_volatile(
+            R8_ADC_CFG,
+            RB_ADC_POWER_ON | RB_ADC_DIFF_EN | RB_ADC_PGA_GAIN_6DB,
+        );
+    }
+    // 第一次转换结果不稳定, 丢弃
+    convert();
+    let sum: u32 = (0..SAMPLES).map(|_| convert() as u32).sum();
+    let calibration = unsafe { core::ptr::read_volatile(ROM_CFG_TMP_25C) };
+    let celsius = adc_to_celsius((sum / SAMPLES) as u16, calibration);
+    unsafe {
+        let adc_cfg = core::ptr::read_volatile(R8_ADC_CFG);
+        core::ptr::write_volatile(R8_ADC_CFG, adc_cfg & !RB_ADC_POWER_ON);
+        let tem_sensor = core::ptr::read_volatile(R8_TEM_SENSOR);
+        core::ptr::write_volatile(R8_TEM_SENSOR, tem_sensor & !RB_TEM_SEN_PWR_ON);
+    }
+    celsius.clamp(i8::MIN as i32, i8::MAX as i32) as i8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calibration() {
+        // 校准温度为0时按25度
+        assert_eq!(adc_to_celsius(2048, 2048), 25);
+        assert_eq!(adc_to_celsius(2048 + 27, 2048), 35);
+        assert_eq!(adc_to_celsius(2048 - 54, 2048), 5);
+        assert_eq!(adc_to_celsius(2048, (23 << 16) | 2048), 23);
+        // 与C一样向0取整
+        assert_eq!(adc_to_celsius(2048 + 26, 2048), 34);
+        assert_eq!(adc_to_celsius(2048 - 26, 2048), 16);
+    }
+}
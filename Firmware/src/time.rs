@@ -0,0 +1,23 @@
+// RTC中的日期时间, 不依赖HAL, 日期格式化和主机上的测试直接使用
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Time {
+    pub year: u8,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub week: u8,
+}
+
+impl Time {
+    // 自1970-01-01起的天数
+    pub fn days_since_epoch(&self) -> u16 {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        NaiveDate::from_ymd_opt(self.year as i32 + 1970, self.month as u32, self.day as u32)
+            .map(|date| (date - epoch).num_days() as u16)
+            .unwrap_or(0)
+    }
+}
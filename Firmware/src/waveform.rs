@@ -0,0 +1,109 @@
+// 按温度选择墨水屏刷新波形(LUT)
+// SSD1607 没有温度补偿, 使用u8g2初始化时写入的固定LUT, 低温下刷新不彻底
+// 这里以常温全刷LUT为基础, 温度越低各阶段的持续时间越长
+// SSD1681 刷新时会根据内部温度传感器自动从OTP加载对应的波形, 不需要处理
+// IL3820 是2.9寸屏, 这里的LUT不适用, 在有对应的LUT之前不做补偿, 见 panel::Compensation
+
+// 1.54寸 GDEP015OC1 常温全刷LUT, 前20字节为电压选择, 后10字节为各阶段时长
+pub const LUT_FULL_UPDATE: [u8; 30] = [
+    0x02, 0x02, 0x01, 0x11, 0x12, 0x12, 0x22, 0x22, 0x66, 0x69, 0x69, 0x59, 0x58, 0x99, 0x99, 0x88,
+    0x00, 0x00, 0x00, 0x00, 0xF8, 0xB4, 0x13, 0x51, 0x35, 0x51, 0x51, 0x19, 0x01, 0x00,
+];
+
+const TIMING_START: usize = 20;
+
+// (温度上限, 时长放大倍数的分子, 分母), 按温度从低到高排列
+const BANDS: [(i8, u8, u8); 2] = [(5, 2, 1), (15, 3, 2)];
+
+// 每个字节的高低4位各是一个阶段的时长, 放大后不超过0xF
+fn scale_timing(lut: &mut [u8; 30], num: u8, den: u8) {
+    let scale = |t: u8| ((t as u16 * num as u16).div_ceil(den as u16)).min(0x0F) as u8;
+    for byte in lut[TIMING_START..].iter_mut() {
+        *byte = (scale(*byte >> 4) << 4) | scale(*byte & 0x0F);
+    }
+}
+
+// 返回需要写入的LUT, None表示常温, 沿用u8g2的默认LUT
+pub fn lut_for(celsius: i8) -> Option<[u8; 30]> {
+    let (_, num, den) = BANDS.iter().find(|(max, _, _)| celsius < *max)?;
+    let mut lut = LUT_FULL_UPDATE;
+    scale_timing(&mut lut, *num, *den);
+    Some(lut)
+}
+
+// SSD1681 4级灰度LUT, 像素按 BW RAM(0x24) 和 RED RAM(0x26) 的两位选择LUT0~LUT3
+// 前153字节写入0x32, 之后依次为 EOPT(0x3F) VGH(0x03) VSH1/VSH2/VSL(0x04) VCOM(0x2C)
+#[rustfmt::skip]
+pub const LUT_4GRAY: [u8; 159] = [
+    0x40, 0x48, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // VS L0
+    0x08, 0x48, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // VS L1
+    0x02, 0x48, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // VS L2
+    0x20, 0x48, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // VS L3
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // VS L4
+    0x0A, 0x19, 0x00, 0x03, 0x08, 0x00, 0x00, // TP, SR, RP of Group0
+    0x14, 0x01, 0x00, 0x14, 0x01, 0x00, 0x03, // TP, SR, RP of Group1
+    0x0A, 0x03, 0x00, 0x08, 0x19, 0x00, 0x01, // TP, SR, RP of Group2
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // TP, SR, RP of Group3
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // TP, SR, RP of Group4
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // TP, SR, RP of Group5
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // TP, SR, RP of Group6
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // TP, SR, RP of Group7
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // TP, SR, RP of Group8
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // TP, SR, RP of Group9
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // TP, SR, RP of Group10
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // TP, SR, RP of Group11
+    0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x00, 0x00, 0x00, // FR, XON
+    0x22, 0x17, 0x41, 0x00, 0x32, 0x1C, // EOPT, VGH, VSH1, VSH2, VSL, VCOM
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 温度上限不包含在区间内: 5度属于第二档, 15度为常温
+    #[test]
+    fn band_edges() {
+        let scaled = |num, den| {
+            let mut lut = LUT_FULL_UPDATE;
+            scale_timing(&mut lut, num, den);
+            lut
+        };
+        for (celsius, expected) in [
+            (i8::MIN, Some(scaled(2, 1))),
+            (4, Some(scaled(2, 1))),
+            (5, Some(scaled(3, 2))),
+            (14, Some(scaled(3, 2))),
+            (15, None),
+            (i8::MAX, None),
+        ] {
+            assert_eq!(lut_for(celsius), expected, "{}", celsius);
+        }
+    }
+
+    // 电压选择部分不变
+    #[test]
+    fn voltages_unchanged() {
+        let lut = lut_for(i8::MIN).unwrap();
+        assert_eq!(lut[..TIMING_START], LUT_FULL_UPDATE[..TIMING_START]);
+    }
+
+    // 高低4位分别向上取整放大, 超过0xF时截断
+    #[test]
+    fn scale_timing_clamps() {
+        let mut lut = [0; 30];
+        lut[TIMING_START..]
+            .copy_from_slice(&[0x00, 0x11, 0x35, 0x78, 0x87, 0xF8, 0x8F, 0xFF, 0x01, 0x10]);
+        let mut double = lut;
+        scale_timing(&mut double, 2, 1);
+        assert_eq!(
+            double[TIMING_START..],
+            [0x00, 0x22, 0x6A, 0xEF, 0xFE, 0xFF, 0xFF, 0xFF, 0x02, 0x20]
+        );
+        let mut half_more = lut;
+        scale_timing(&mut half_more, 3, 2);
+        assert_eq!(
+            half_more[TIMING_START..],
+            [0x00, 0x22, 0x58, 0xBC, 0xCB, 0xFC, 0xCF, 0xFF, 0x02, 0x20]
+        );
+    }
+}
@@ -0,0 +1,151 @@
+#!/usr/bin/env python3
+"""把固件通过串口输出的截图(PBM或XBM文本)转换成PNG.
+
+通过广播 `Sc1R` 打开截图设置后, 每次刷新都会在UART1上输出一段截图:
+
+    python3 tools/screenshot.py serial.log -o screen.png
+    python3 tools/screenshot.py --port /dev/ttyUSB0 -o screen.png   # 需要 pyserial
+
+日志里有多张截图时取最后一张. u8g2缓冲区是屏幕原始方向, 固件在截图开头写出当前的旋转和镜像设置,
+截图会按此还原成实际看到的方向. 旧固件没有这一行时用 --rotate/--mirror 指定.
+只依赖标准库.
+"""
+import argparse
+import re
+import struct
+import sys
+import zlib
+
+BEGIN = "-----BEGIN SCREENSHOT-----"
+END = "-----END SCREENSHOT-----"
+
+
+def extract(text):
+    """返回日志中最后一段完整的截图文本"""
+    end = text.rfind(END)
+    if end < 0:
+        raise ValueError("no screenshot found")
+    begin = text.rfind(BEGIN, 0, end)
+    if begin < 0:
+        raise ValueError("screenshot begin marker missing")
+    return text[begin + len(BEGIN):end]
+
+
+def parse_pbm(text):
+    tokens = text.split()
+    if tokens[0] != "P1":
+        raise ValueError("not a plain PBM")
+    width, height = int(tokens[1]), int(tokens[2])
+    bits = [c for c in "".join(tokens[3:]) if c in "01"]
+    if len(bits) < width * height:
+        raise ValueError("truncated PBM: %d of %d pixels" % (len(bits), width * height))
+    return width, height, [[bits[y * width + x] == "1" for x in range(width)] for y in range(height)]
+
+
+def parse_xbm(text):
+    width = int(re.search(r"_width\s+(\d+)", text).group(1))
+    height = int(re.search(r"_height\s+(\d+)", text).group(1))
+    data = [int(v, 16) for v in re.findall(r"0x([0-9a-fA-F]{1,2})", text.split("{", 1)[1])]
+    stride = (width + 7) // 8
+    if len(data) < stride * height:
+        raise ValueError("truncated XBM: %d of %d bytes" % (len(data), stride * height))
+    rows = []
+    for y in range(height):
+        row = data[y * stride:(y + 1) * stride]
+        rows.append([bool(row[x // 8] >> (x % 8) & 1) for x in range(width)])
+    return width, height, rows
+
+
+def parse_orientation(text):
+    """截图开头的 "rotation 270 mirror 1", 返回 (角度, 镜像, 剩下的文本), 没有这一行时角度和镜像为 None"""
+    m = re.match(r"\s*rotation (\d+) mirror ([01])\s*\n", text)
+    if not m:
+        return None, None, text
+    return int(m.group(1)), m.group(2) == "1", text[m.end():]
+
+
+def parse(text):
+    if text.lstrip().startswith("P1"):
+        return parse_pbm(text)
+    return parse_xbm(text)
+
+
+def rotate(pixels, degrees):
+    """顺时针旋转"""
+    for _ in range(degrees // 90 % 4):
+        pixels = [list(row) for row in zip(*pixels[::-1])]
+    return pixels
+
+
+def unmirror(pixels):
+    """镜像是在旋转后的坐标系里左右翻转"""
+    return [row[::-1] for row in pixels]
+
+
+def write_png(path, pixels, scale):
+    """1为黑色, 输出8位灰度PNG"""
+    rows = []
+    for row in pixels:
+        line = bytes(0 if p else 255 for p in row for _ in range(scale))
+        rows.extend([line] * scale)
+    width, height = len(rows[0]), len(rows)
+
+    def chunk(kind, data):
+        body = kind + data
+        return struct.pack(">I", len(data)) + body + struct.pack(">I", zlib.crc32(body) & 0xFFFFFFFF)
+
+    raw = b"".join(b"\x00" + line for line in rows)
+    with open(path, "wb") as f:
+        f.write(b"\x89PNG\r\n\x1a\n")
+        f.write(chunk(b"IHDR", struct.pack(">IIBBBBB", width, height, 8, 0, 0, 0, 0)))
+        f.write(chunk(b"IDAT", zlib.compress(raw, 9)))
+        f.write(chunk(b"IEND", b""))
+
+
+def read_port(port, baudrate):
+    import serial
+
+    text = ""
+    with serial.Serial(port, baudrate, timeout=30) as s:
+        while END not in text:
+            line = s.readline()
+            if not line:
+                raise TimeoutError("no screenshot received")
+            text += line.decode("ascii", "replace")
+    return text
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("log", nargs="?", help="串口日志文件, 省略时从标准输入读取")
+    parser.add_argument("--port", help="直接从串口读取")
+    parser.add_argument("--baudrate", type=int, default=115200)
+    parser.add_argument("-o", "--output", default="screenshot.png")
+    parser.add_argument("--rotate", type=int, choices=[0, 90, 180, 270], help="旋转方向, 默认使用截图中记录的设置")
+    parser.add_argument("--mirror", type=int, choices=[0, 1], help="是否镜像, 默认使用截图中记录的设置")
+    parser.add_argument("--scale", type=int, default=2)
+    args = parser.parse_args()
+
+    if args.port:
+        text = read_port(args.port, args.baudrate)
+    elif args.log:
+        with open(args.log, encoding="ascii", errors="replace") as f:
+            text = f.read()
+    else:
+        text = sys.stdin.read()
+
+    rotation, mirror, body = parse_orientation(extract(text))
+    if args.rotate is not None:
+        rotation = args.rotate
+    if args.mirror is not None:
+        mirror = bool(args.mirror)
+    width, height, pixels = parse(body)
+    pixels = rotate(pixels, (360 - (rotation or 0)) % 360)
+    if mirror:
+        pixels = unmirror(pixels)
+    write_png(args.output, pixels, args.scale)
+    print("%dx%d -> %s" % (width, height, args.output))
+
+
+if __name__ == "__main__":
+    main()
@@ -33,6 +33,7 @@ fn main() {
         "u8g2/csrc/u8x8_byte.c",
         "u8g2/csrc/u8x8_cad.c",
         "u8g2/csrc/u8x8_capture.c",
+        "u8g2/csrc/u8x8_d_il3820_296x128.c",
         "u8g2/csrc/u8x8_d_ssd1607_200x200.c",
         "u8g2/csrc/u8x8_d_ssd1681_200x200.c",
         "u8g2/csrc/u8x8_debounce.c",